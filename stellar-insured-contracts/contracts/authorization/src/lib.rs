@@ -5,14 +5,20 @@
 //!
 //! ## Features
 //! - Standardized role definitions across all contracts
+//! - Multiple roles per address, stored as a role bitmask
+//! - Optional expiry on role grants
+//! - Per-role member lists and an append-only role audit log
+//! - Delegated operator keys acting for a role holder on selected functions
 //! - Explicit permission checking for privileged operations
-//! - Cross-contract call validation
+//! - Cross-contract call validation, optionally scoped to specific functions
 //! - Least-privilege enforcement
 //! - Audit trail support
+//! - Optional M-of-N multisig admin mode
+//! - Two-step admin transfer and role renunciation
 
 #![no_std]
 
-use soroban_sdk::{contracttype, Address, Env};
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, BytesN, Env, IntoVal, Symbol, Val, Vec};
 
 /// Protocol-wide role definitions
 #[contracttype]
@@ -36,12 +42,136 @@ pub enum Role {
 #[contracttype]
 #[derive(Clone)]
 pub enum RoleKey {
-    /// Maps Address -> Role
+    /// Maps Address -> Role (legacy single-role entry, migrated to `UserRoles` on first write)
     UserRole(Address),
+    /// Maps Address -> bitmask of held roles, see `Role::bit`
+    UserRoles(Address),
+    /// Expiry timestamp of a time-bounded role grant (absent for permanent grants)
+    RoleExpiry(Address, Role),
+    /// Addresses holding a role (including grants that expired but were not revoked)
+    RoleMembers(Role),
+    /// Role audit log entry by id
+    RoleAuditEntry(u64),
+    /// Number of role audit log entries
+    RoleAuditCount,
     /// Contract-level admin address
     ContractAdmin,
     /// Trusted contract addresses for cross-contract calls
     TrustedContract(Address),
+    /// Functions a trusted contract may call (absent for legacy unscoped trust)
+    TrustedFunctions(Address),
+    /// Multisig signers and threshold (present only in multisig admin mode)
+    MultisigConfig,
+    /// Multisig admin proposal by id
+    AdminProposal(u64),
+    /// Last assigned multisig admin proposal id
+    AdminProposalCounter,
+    /// Number of executed, not yet consumed approvals for a contract-specific admin
+    /// action, keyed by its function and the hash of its arguments
+    ApprovedAdminAction(Symbol, BytesN<32>),
+    /// Admin transfer awaiting acceptance by the new admin
+    PendingAdmin,
+    /// Operator delegation, keyed by the delegate address
+    Delegation(Address),
+}
+
+/// Secondary key allowed to act for a role holder on some functions during a time window
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Delegation {
+    pub principal: Address,
+    pub delegate: Address,
+    pub functions: Vec<Symbol>,
+    pub valid_from: u64,
+    /// The delegation lapses at this timestamp
+    pub valid_until: u64,
+}
+
+/// Role held by an address, as listed for audits
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleGrant {
+    pub role: Role,
+    /// The grant lapses at this timestamp; `None` for permanent grants
+    pub expires_at: Option<u64>,
+}
+
+/// Kind of role change recorded in the audit log
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoleAuditAction {
+    Granted = 0,
+    Revoked = 1,
+    Renounced = 2,
+    /// Admin role handed over; `actor` is the previous admin, `target` the new one
+    Transferred = 3,
+}
+
+/// Role audit log entry
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleAuditEntry {
+    pub id: u64,
+    pub action: RoleAuditAction,
+    pub actor: Address,
+    pub target: Address,
+    pub role: Role,
+    pub timestamp: u64,
+}
+
+/// Maximum number of entries returned by a single paginated role query
+pub const MAX_ROLE_PAGE_SIZE: u32 = 50;
+
+/// Admin transfer proposed by the current admin
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingAdminTransfer {
+    pub current_admin: Address,
+    pub new_admin: Address,
+    /// The transfer can no longer be accepted after this timestamp
+    pub expires_at: u64,
+}
+
+/// Signers and approval threshold of the multisig admin
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultisigConfig {
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+}
+
+/// Admin action proposed to the multisig
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AdminAction {
+    /// Grant a role to an address, optionally until an expiry timestamp
+    GrantRole(Address, Role, Option<u64>),
+    /// Revoke a single role from an address
+    RevokeRole(Address, Role),
+    /// Trust a contract for every entry point (explicit opt-in, prefer `RegisterTrustedContractFor`)
+    RegisterUnscopedTrustedContract(Address),
+    /// Trust a contract for the listed functions only
+    RegisterTrustedContractFor(Address, Vec<Symbol>),
+    /// Stop trusting a contract
+    UnregisterTrustedContract(Address),
+    /// Replace the signer set and threshold
+    RotateSigners(Vec<Address>, u32),
+    /// Contract-specific admin entry point, named by its function and bound to its
+    /// arguments by `admin_action_hash`; consumed by `require_admin_action` when a
+    /// signer calls that entry point with the same arguments
+    Contract(Symbol, BytesN<32>),
+}
+
+/// Multisig admin proposal and the signers that approved it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminProposal {
+    pub id: u64,
+    pub action: AdminAction,
+    pub proposer: Address,
+    pub approvals: Vec<Address>,
+    pub created_at: u64,
+    pub executed: bool,
 }
 
 /// Authorization errors
@@ -51,10 +181,35 @@ pub enum AuthError {
     InvalidRole,
     RoleNotFound,
     NotTrustedContract,
+    InvalidInput,
+    NotFound,
+    AlreadyExists,
+    InvalidState,
 }
 
+/// Every role that can be granted, in order of decreasing privilege
+const GRANTABLE_ROLES: [Role; 5] = [
+    Role::Admin,
+    Role::Governance,
+    Role::RiskPoolManager,
+    Role::PolicyManager,
+    Role::ClaimProcessor,
+];
+
 /// Permission matrix: defines what each role can do
 impl Role {
+    /// Bit of this role in an address's role mask (`User` is the empty mask)
+    pub fn bit(&self) -> u32 {
+        match self {
+            Role::Admin => 1 << 0,
+            Role::Governance => 1 << 1,
+            Role::RiskPoolManager => 1 << 2,
+            Role::PolicyManager => 1 << 3,
+            Role::ClaimProcessor => 1 << 4,
+            Role::User => 0,
+        }
+    }
+
     /// Check if this role has permission for administrative actions
     pub fn can_admin(&self) -> bool {
         matches!(self, Role::Admin)
@@ -93,83 +248,380 @@ pub fn initialize_admin(env: &Env, admin: Address) {
     env.storage()
         .persistent()
         .set(&RoleKey::ContractAdmin, &admin);
-    env.storage()
-        .persistent()
-        .set(&RoleKey::UserRole(admin.clone()), &Role::Admin);
+    add_role(env, &admin, Role::Admin, None);
+    record_role_audit(env, RoleAuditAction::Granted, &admin, &admin, Role::Admin);
 }
 
-/// Get the contract admin address
+/// Get the contract admin address (`None` before initialization and in multisig admin mode)
 pub fn get_admin(env: &Env) -> Option<Address> {
     env.storage()
         .persistent()
         .get(&RoleKey::ContractAdmin)
 }
 
-/// Grant a role to an address (admin only)
-pub fn grant_role(env: &Env, caller: &Address, target: &Address, role: Role) -> Result<(), AuthError> {
-    // Verify caller is admin
-    require_role(env, caller, Role::Admin)?;
-    
-    // Grant the role
+/// Check if the admin was set up, either as a single admin or as a multisig
+pub fn is_admin_initialized(env: &Env) -> bool {
+    env.storage().persistent().has(&RoleKey::ContractAdmin) || is_multisig_enabled(env)
+}
+
+/// Role mask of an address, falling back to a legacy single-role entry
+fn get_role_mask(env: &Env, address: &Address) -> u32 {
+    if let Some(mask) = env
+        .storage()
+        .persistent()
+        .get(&RoleKey::UserRoles(address.clone()))
+    {
+        return mask;
+    }
+
     env.storage()
         .persistent()
-        .set(&RoleKey::UserRole(target.clone()), &role);
-    
-    Ok(())
+        .get::<_, Role>(&RoleKey::UserRole(address.clone()))
+        .map(|role| role.bit())
+        .unwrap_or(0)
 }
 
-/// Revoke a role from an address (admin only)
-pub fn revoke_role(env: &Env, caller: &Address, target: &Address) -> Result<(), AuthError> {
-    // Verify caller is admin
-    require_role(env, caller, Role::Admin)?;
-    
-    // Prevent admin from revoking their own role (safeguard)
-    if caller == target {
-        return Err(AuthError::Unauthorized);
+/// Store the role mask of an address, replacing any legacy single-role entry
+fn set_role_mask(env: &Env, address: &Address, mask: u32) {
+    // Legacy entries were never listed as members, so diff against the stored mask only
+    let previous: u32 = env
+        .storage()
+        .persistent()
+        .get(&RoleKey::UserRoles(address.clone()))
+        .unwrap_or(0);
+    for role in GRANTABLE_ROLES.iter() {
+        let bit = role.bit();
+        if (previous ^ mask) & bit != 0 {
+            update_role_members(env, role, address, mask & bit != 0);
+        }
     }
-    
-    // Revoke by setting to User role (lowest privilege)
+
     env.storage()
         .persistent()
-        .set(&RoleKey::UserRole(target.clone()), &Role::User);
-    
+        .remove(&RoleKey::UserRole(address.clone()));
+    if mask == 0 {
+        env.storage()
+            .persistent()
+            .remove(&RoleKey::UserRoles(address.clone()));
+    } else {
+        env.storage()
+            .persistent()
+            .set(&RoleKey::UserRoles(address.clone()), &mask);
+    }
+}
+
+fn update_role_members(env: &Env, role: &Role, address: &Address, is_member: bool) {
+    let key = RoleKey::RoleMembers(role.clone());
+    let mut members: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env));
+    match (members.first_index_of(address), is_member) {
+        (None, true) => members.push_back(address.clone()),
+        (Some(index), false) => {
+            members.remove(index);
+        }
+        _ => return,
+    }
+    env.storage().persistent().set(&key, &members);
+}
+
+/// Append an entry to the role audit log
+fn record_role_audit(env: &Env, action: RoleAuditAction, actor: &Address, target: &Address, role: Role) {
+    let id: u64 = env
+        .storage()
+        .persistent()
+        .get(&RoleKey::RoleAuditCount)
+        .unwrap_or(0);
+    let entry = RoleAuditEntry {
+        id,
+        action,
+        actor: actor.clone(),
+        target: target.clone(),
+        role,
+        timestamp: env.ledger().timestamp(),
+    };
+    env.storage()
+        .persistent()
+        .set(&RoleKey::RoleAuditEntry(id), &entry);
+    env.storage()
+        .persistent()
+        .set(&RoleKey::RoleAuditCount, &(id + 1));
+}
+
+fn page_size(limit: u32) -> u32 {
+    if limit == 0 || limit > MAX_ROLE_PAGE_SIZE {
+        MAX_ROLE_PAGE_SIZE
+    } else {
+        limit
+    }
+}
+
+fn get_role_expiry(env: &Env, address: &Address, role: &Role) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&RoleKey::RoleExpiry(address.clone(), role.clone()))
+}
+
+/// Role mask of an address without the grants that have expired
+fn active_role_mask(env: &Env, address: &Address) -> u32 {
+    let mut mask = get_role_mask(env, address);
+    let now = env.ledger().timestamp();
+    for role in GRANTABLE_ROLES.iter() {
+        if mask & role.bit() == 0 {
+            continue;
+        }
+        if let Some(expires_at) = get_role_expiry(env, address, role) {
+            if now >= expires_at {
+                mask &= !role.bit();
+            }
+        }
+    }
+    mask
+}
+
+fn add_role(env: &Env, address: &Address, role: Role, expires_at: Option<u64>) {
+    let key = RoleKey::RoleExpiry(address.clone(), role.clone());
+    match expires_at {
+        Some(expires_at) => env.storage().persistent().set(&key, &expires_at),
+        None => env.storage().persistent().remove(&key),
+    }
+    set_role_mask(env, address, get_role_mask(env, address) | role.bit());
+}
+
+fn remove_role(env: &Env, address: &Address, role: Role) {
+    env.storage()
+        .persistent()
+        .remove(&RoleKey::RoleExpiry(address.clone(), role.clone()));
+    set_role_mask(env, address, get_role_mask(env, address) & !role.bit());
+}
+
+/// Check a role grant before storing it: `User` cannot be granted, `Admin` cannot
+/// be time-bounded and an expiry must lie in the future
+fn validate_grant(env: &Env, role: &Role, expires_at: Option<u64>) -> Result<(), AuthError> {
+    // `User` is the absence of roles and cannot be granted
+    if *role == Role::User {
+        return Err(AuthError::InvalidRole);
+    }
+    if let Some(expires_at) = expires_at {
+        if *role == Role::Admin || expires_at <= env.ledger().timestamp() {
+            return Err(AuthError::InvalidInput);
+        }
+    }
     Ok(())
 }
 
-/// Get the role of an address
+/// Grant a role to an address, keeping the roles it already holds (admin only).
+/// With `expires_at` the grant lapses at that timestamp; re-granting replaces the expiry.
+/// In multisig mode the caller consumes an approved `grant_role` action for these arguments.
+pub fn grant_role(
+    env: &Env,
+    caller: &Address,
+    target: &Address,
+    role: Role,
+    expires_at: Option<u64>,
+) -> Result<(), AuthError> {
+    require_admin_action(
+        env,
+        caller,
+        &Symbol::new(env, "grant_role"),
+        (target.clone(), role.clone(), expires_at).into_val(env),
+    )?;
+
+    validate_grant(env, &role, expires_at)?;
+    add_role(env, target, role.clone(), expires_at);
+    record_role_audit(env, RoleAuditAction::Granted, caller, target, role);
+
+    Ok(())
+}
+
+/// Revoke a single role from an address, keeping its other roles (admin only).
+/// In multisig mode the caller consumes an approved `revoke_role` action for these arguments.
+pub fn revoke_role(env: &Env, caller: &Address, target: &Address, role: Role) -> Result<(), AuthError> {
+    require_admin_action(
+        env,
+        caller,
+        &Symbol::new(env, "revoke_role"),
+        (target.clone(), role.clone()).into_val(env),
+    )?;
+
+    // Prevent admin from revoking their own admin role (safeguard)
+    if caller == target && role == Role::Admin {
+        return Err(AuthError::Unauthorized);
+    }
+
+    // Expired grants can still be revoked to clean them up
+    if get_role_mask(env, target) & role.bit() == 0 {
+        return Err(AuthError::RoleNotFound);
+    }
+
+    remove_role(env, target, role.clone());
+    record_role_audit(env, RoleAuditAction::Revoked, caller, target, role);
+
+    Ok(())
+}
+
+/// Get all active roles held by an address, most privileged first (empty for plain users)
+pub fn get_roles(env: &Env, address: &Address) -> Vec<Role> {
+    let mask = active_role_mask(env, address);
+    let mut roles = Vec::new(env);
+    for role in GRANTABLE_ROLES.iter() {
+        if mask & role.bit() != 0 {
+            roles.push_back(role.clone());
+        }
+    }
+    roles
+}
+
+/// Get the most privileged role of an address (`User` if it holds none)
 pub fn get_role(env: &Env, address: &Address) -> Role {
-    env.storage()
+    get_roles(env, address).first().unwrap_or(Role::User)
+}
+
+/// Get the active role grants of an address with their expiry
+pub fn get_role_grants(env: &Env, address: &Address) -> Vec<RoleGrant> {
+    let mut grants = Vec::new(env);
+    for role in get_roles(env, address).iter() {
+        let expires_at = get_role_expiry(env, address, &role);
+        grants.push_back(RoleGrant { role, expires_at });
+    }
+    grants
+}
+
+/// Get the holders of a role, `limit` (capped at `MAX_ROLE_PAGE_SIZE`) list entries from
+/// `start_index`. Holders whose grant expired are skipped, so a page may come back short.
+pub fn get_role_members(env: &Env, role: Role, start_index: u32, limit: u32) -> Vec<Address> {
+    let members: Vec<Address> = env
+        .storage()
         .persistent()
-        .get(&RoleKey::UserRole(address.clone()))
-        .unwrap_or(Role::User) // Default to User if no role assigned
+        .get(&RoleKey::RoleMembers(role.clone()))
+        .unwrap_or_else(|| Vec::new(env));
+
+    let mut page = Vec::new(env);
+    let end_index = core::cmp::min(start_index.saturating_add(page_size(limit)), members.len());
+    for i in start_index..end_index {
+        let member = members.get(i).unwrap();
+        if has_role(env, &member, role.clone()) {
+            page.push_back(member);
+        }
+    }
+    page
 }
 
-/// Check if an address has a specific role
+/// Get up to `limit` (capped at `MAX_ROLE_PAGE_SIZE`) role audit log entries, oldest first,
+/// starting at entry id `cursor`
+pub fn get_role_audit_log(env: &Env, cursor: u64, limit: u32) -> Vec<RoleAuditEntry> {
+    let count: u64 = env
+        .storage()
+        .persistent()
+        .get(&RoleKey::RoleAuditCount)
+        .unwrap_or(0);
+
+    let mut entries = Vec::new(env);
+    let end = core::cmp::min(cursor.saturating_add(page_size(limit) as u64), count);
+    for id in cursor..end {
+        if let Some(entry) = env.storage().persistent().get(&RoleKey::RoleAuditEntry(id)) {
+            entries.push_back(entry);
+        }
+    }
+    entries
+}
+
+/// Rewrite a legacy single-role entry as a role mask. Idempotent; returns whether anything changed.
+pub fn migrate_role_entry(env: &Env, address: &Address) -> bool {
+    let legacy: Option<Role> = env
+        .storage()
+        .persistent()
+        .get(&RoleKey::UserRole(address.clone()));
+    match legacy {
+        Some(_) => {
+            set_role_mask(env, address, get_role_mask(env, address));
+            true
+        }
+        None => false,
+    }
+}
+
+/// Check if an address has a specific role; expired grants count as absent
 pub fn has_role(env: &Env, address: &Address, required_role: Role) -> bool {
-    let user_role = get_role(env, address);
-    user_role == required_role
+    if required_role == Role::User {
+        return true;
+    }
+    active_role_mask(env, address) & required_role.bit() != 0
 }
 
 /// Require that the caller has a specific role (throws error if not)
 pub fn require_role(env: &Env, address: &Address, required_role: Role) -> Result<(), AuthError> {
-    let user_role = get_role(env, address);
-    
-    if user_role == required_role {
+    if has_role(env, address, required_role) {
         Ok(())
     } else {
         Err(AuthError::Unauthorized)
     }
 }
 
+/// Check whether any role held by an address (or `User` if none) passes `permission_check`
+fn any_role_permits<F>(env: &Env, address: &Address, permission_check: F) -> bool
+where
+    F: Fn(&Role) -> bool,
+{
+    let roles = get_roles(env, address);
+    if roles.is_empty() {
+        return permission_check(&Role::User);
+    }
+    roles.iter().any(|role| permission_check(&role))
+}
+
 /// Require admin privileges
 pub fn require_admin(env: &Env, address: &Address) -> Result<(), AuthError> {
     require_role(env, address, Role::Admin)
 }
 
+/// Hash binding a contract admin action to its arguments, as approved by
+/// `AdminAction::Contract(function, hash)`
+pub fn admin_action_hash(env: &Env, function: &Symbol, args: &Vec<Val>) -> BytesN<32> {
+    env.crypto()
+        .sha256(&(function.clone(), args.clone()).to_xdr(env))
+        .into()
+}
+
+/// Require admin privileges for the admin entry point `action` called with `args`
+/// (the entry point's arguments without the caller).
+/// In multisig mode the caller must be a signer and consumes one executed approval
+/// for `action` with the same arguments.
+pub fn require_admin_action(
+    env: &Env,
+    caller: &Address,
+    action: &Symbol,
+    args: Vec<Val>,
+) -> Result<(), AuthError> {
+    let config = match get_multisig_config(env) {
+        Some(config) => config,
+        None => return require_admin(env, caller),
+    };
+
+    if !config.signers.contains(caller) {
+        return Err(AuthError::Unauthorized);
+    }
+
+    let key = RoleKey::ApprovedAdminAction(action.clone(), admin_action_hash(env, action, &args));
+    let approved: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+    match approved {
+        0 => Err(AuthError::Unauthorized),
+        1 => {
+            env.storage().persistent().remove(&key);
+            Ok(())
+        }
+        _ => {
+            env.storage().persistent().set(&key, &(approved - 1));
+            Ok(())
+        }
+    }
+}
+
 /// Check if an address has any of the specified roles
 pub fn has_any_role(env: &Env, address: &Address, roles: &[Role]) -> bool {
-    let user_role = get_role(env, address);
-    roles.contains(&user_role)
+    roles.iter().any(|role| has_role(env, address, role.clone()))
 }
 
 /// Require that the caller has one of the specified roles
@@ -185,8 +637,7 @@ pub fn require_any_role(env: &Env, address: &Address, roles: &[Role]) -> Result<
 
 /// Require permission to manage policies
 pub fn require_policy_management(env: &Env, address: &Address) -> Result<(), AuthError> {
-    let role = get_role(env, address);
-    if role.can_manage_policies() {
+    if any_role_permits(env, address, Role::can_manage_policies) {
         Ok(())
     } else {
         Err(AuthError::Unauthorized)
@@ -195,8 +646,7 @@ pub fn require_policy_management(env: &Env, address: &Address) -> Result<(), Aut
 
 /// Require permission to process claims
 pub fn require_claim_processing(env: &Env, address: &Address) -> Result<(), AuthError> {
-    let role = get_role(env, address);
-    if role.can_process_claims() {
+    if any_role_permits(env, address, Role::can_process_claims) {
         Ok(())
     } else {
         Err(AuthError::Unauthorized)
@@ -205,8 +655,7 @@ pub fn require_claim_processing(env: &Env, address: &Address) -> Result<(), Auth
 
 /// Require permission to manage risk pool
 pub fn require_risk_pool_management(env: &Env, address: &Address) -> Result<(), AuthError> {
-    let role = get_role(env, address);
-    if role.can_manage_risk_pool() {
+    if any_role_permits(env, address, Role::can_manage_risk_pool) {
         Ok(())
     } else {
         Err(AuthError::Unauthorized)
@@ -215,8 +664,7 @@ pub fn require_risk_pool_management(env: &Env, address: &Address) -> Result<(),
 
 /// Require permission to participate in governance
 pub fn require_governance_permission(env: &Env, address: &Address) -> Result<(), AuthError> {
-    let role = get_role(env, address);
-    if role.can_govern() {
+    if any_role_permits(env, address, Role::can_govern) {
         Ok(())
     } else {
         Err(AuthError::Unauthorized)
@@ -225,10 +673,21 @@ pub fn require_governance_permission(env: &Env, address: &Address) -> Result<(),
 
 /// Cross-contract call validation
 
-/// Register a trusted contract address (admin only)
-pub fn register_trusted_contract(env: &Env, caller: &Address, contract_address: &Address) -> Result<(), AuthError> {
-    require_admin(env, caller)?;
-    
+/// Trust a contract for every entry point (admin only). Unscoped trust is an explicit
+/// opt-in for counterparties without a fixed set of calls; prefer `register_trusted_contract_for`.
+/// In multisig mode the caller consumes an approved action for these arguments.
+pub fn register_unscoped_trust(
+    env: &Env,
+    caller: &Address,
+    contract_address: &Address,
+) -> Result<(), AuthError> {
+    require_admin_action(
+        env,
+        caller,
+        &Symbol::new(env, "register_unscoped_trust"),
+        (contract_address.clone(),).into_val(env),
+    )?;
+
     env.storage()
         .persistent()
         .set(&RoleKey::TrustedContract(contract_address.clone()), &true);
@@ -236,17 +695,71 @@ pub fn register_trusted_contract(env: &Env, caller: &Address, contract_address:
     Ok(())
 }
 
-/// Unregister a trusted contract address (admin only)
-pub fn unregister_trusted_contract(env: &Env, caller: &Address, contract_address: &Address) -> Result<(), AuthError> {
-    require_admin(env, caller)?;
-    
+/// Register a trusted contract that may only call the listed functions (admin only).
+/// Replaces any previous allowlist for the contract.
+/// In multisig mode the caller consumes an approved action for these arguments.
+pub fn register_trusted_contract_for(
+    env: &Env,
+    caller: &Address,
+    contract_address: &Address,
+    functions: Vec<Symbol>,
+) -> Result<(), AuthError> {
+    require_admin_action(
+        env,
+        caller,
+        &Symbol::new(env, "register_trusted_contract_for"),
+        (contract_address.clone(), functions.clone()).into_val(env),
+    )?;
+
+    set_trusted_functions(env, contract_address, functions)
+}
+
+fn set_trusted_functions(env: &Env, contract_address: &Address, functions: Vec<Symbol>) -> Result<(), AuthError> {
+    if functions.is_empty() {
+        return Err(AuthError::InvalidInput);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&RoleKey::TrustedContract(contract_address.clone()), &true);
+    env.storage()
+        .persistent()
+        .set(&RoleKey::TrustedFunctions(contract_address.clone()), &functions);
+
+    Ok(())
+}
+
+fn remove_trusted_contract(env: &Env, contract_address: &Address) {
     env.storage()
         .persistent()
         .remove(&RoleKey::TrustedContract(contract_address.clone()));
+    env.storage()
+        .persistent()
+        .remove(&RoleKey::TrustedFunctions(contract_address.clone()));
+}
+
+/// Unregister a trusted contract address (admin only).
+/// In multisig mode the caller consumes an approved action for these arguments.
+pub fn unregister_trusted_contract(env: &Env, caller: &Address, contract_address: &Address) -> Result<(), AuthError> {
+    require_admin_action(
+        env,
+        caller,
+        &Symbol::new(env, "unregister_trusted_contract"),
+        (contract_address.clone(),).into_val(env),
+    )?;
+    
+    remove_trusted_contract(env, contract_address);
     
     Ok(())
 }
 
+/// Get the functions a trusted contract is limited to (`None` if unscoped or untrusted)
+pub fn get_trusted_functions(env: &Env, contract_address: &Address) -> Option<Vec<Symbol>> {
+    env.storage()
+        .persistent()
+        .get(&RoleKey::TrustedFunctions(contract_address.clone()))
+}
+
 /// Check if a contract address is trusted
 pub fn is_trusted_contract(env: &Env, contract_address: &Address) -> bool {
     env.storage()
@@ -264,27 +777,935 @@ pub fn require_trusted_contract(env: &Env, contract_address: &Address) -> Result
     }
 }
 
+/// Require that the calling contract is trusted for `function`. Contracts registered
+/// with an allowlist may only call the listed functions; legacy and explicitly unscoped
+/// registrations keep full access until re-registered with `register_trusted_contract_for`.
+pub fn require_trusted_contract_for(env: &Env, caller: &Address, function: &Symbol) -> Result<(), AuthError> {
+    require_trusted_contract(env, caller)?;
+
+    match get_trusted_functions(env, caller) {
+        Some(functions) if !functions.contains(function) => Err(AuthError::NotTrustedContract),
+        _ => Ok(()),
+    }
+}
+
 /// Utility: Combine identity verification with role check
-/// This is the recommended pattern for most privileged operations
-pub fn verify_and_require_role(env: &Env, caller: &Address, required_role: Role) -> Result<(), AuthError> {
+/// This is the recommended pattern for most privileged operations.
+/// `caller` may be the role holder or one of its delegates for `function`;
+/// returns the principal the call is made for.
+pub fn verify_and_require_role(
+    env: &Env,
+    caller: &Address,
+    required_role: Role,
+    function: &Symbol,
+) -> Result<Address, AuthError> {
     // First, verify the caller's identity (Soroban's built-in auth)
     caller.require_auth();
-    
-    // Then, check if they have the required role
-    require_role(env, caller, required_role)
+
+    // Then, check if they (or the principal they act for) have the required role
+    resolve_principal(env, caller, function, |address| {
+        has_role(env, address, required_role.clone())
+    })
 }
 
-/// Utility: Verify identity and check permission
-pub fn verify_and_check_permission<F>(env: &Env, caller: &Address, permission_check: F) -> Result<(), AuthError>
+/// Utility: Verify identity and check permission, accepting delegates like `verify_and_require_role`
+pub fn verify_and_check_permission<F>(
+    env: &Env,
+    caller: &Address,
+    function: &Symbol,
+    permission_check: F,
+) -> Result<Address, AuthError>
 where
     F: Fn(&Role) -> bool,
 {
     caller.require_auth();
-    
-    let role = get_role(env, caller);
-    if permission_check(&role) {
-        Ok(())
+
+    resolve_principal(env, caller, function, |address| {
+        any_role_permits(env, address, &permission_check)
+    })
+}
+
+/// Find who `caller` acts for and emit a `role_action` event naming the principal and the signer
+fn resolve_principal<F>(env: &Env, caller: &Address, function: &Symbol, is_permitted: F) -> Result<Address, AuthError>
+where
+    F: Fn(&Address) -> bool,
+{
+    let principal = if is_permitted(caller) {
+        caller.clone()
     } else {
-        Err(AuthError::Unauthorized)
+        match get_active_delegation(env, caller, function) {
+            Some(delegation) if is_permitted(&delegation.principal) => delegation.principal,
+            _ => return Err(AuthError::Unauthorized),
+        }
+    };
+
+    env.events().publish(
+        (Symbol::new(env, "role_action"), function.clone(), principal.clone()),
+        caller.clone(),
+    );
+
+    Ok(principal)
+}
+
+// Delegated operator keys
+
+/// Authorize `delegate` to call `functions` on behalf of the principal between
+/// `valid_from` and `valid_until` (role holders only). Replaces the principal's
+/// previous delegation to the same key.
+pub fn delegate_operator(
+    env: &Env,
+    principal: &Address,
+    delegate: &Address,
+    functions: Vec<Symbol>,
+    valid_from: u64,
+    valid_until: u64,
+) -> Result<(), AuthError> {
+    principal.require_auth();
+
+    if get_roles(env, principal).is_empty() {
+        return Err(AuthError::RoleNotFound);
+    }
+    if principal == delegate
+        || functions.is_empty()
+        || valid_until <= valid_from
+        || valid_until <= env.ledger().timestamp()
+    {
+        return Err(AuthError::InvalidInput);
+    }
+    // A key acts for a single principal at a time
+    if let Some(existing) = get_delegation(env, delegate) {
+        if &existing.principal != principal {
+            return Err(AuthError::AlreadyExists);
+        }
+    }
+
+    env.storage().persistent().set(
+        &RoleKey::Delegation(delegate.clone()),
+        &Delegation {
+            principal: principal.clone(),
+            delegate: delegate.clone(),
+            functions,
+            valid_from,
+            valid_until,
+        },
+    );
+
+    env.events().publish(
+        (Symbol::new(env, "operator_delegated"), principal.clone()),
+        (delegate.clone(), valid_from, valid_until),
+    );
+
+    Ok(())
+}
+
+/// Withdraw a delegation before it lapses (principal only)
+pub fn revoke_delegation(env: &Env, principal: &Address, delegate: &Address) -> Result<(), AuthError> {
+    principal.require_auth();
+
+    let delegation = get_delegation(env, delegate).ok_or(AuthError::NotFound)?;
+    if &delegation.principal != principal {
+        return Err(AuthError::Unauthorized);
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&RoleKey::Delegation(delegate.clone()));
+
+    env.events().publish(
+        (Symbol::new(env, "delegation_revoked"), principal.clone()),
+        delegate.clone(),
+    );
+
+    Ok(())
+}
+
+/// Get the delegation held by a key, if any (lapsed delegations are still returned)
+pub fn get_delegation(env: &Env, delegate: &Address) -> Option<Delegation> {
+    env.storage()
+        .persistent()
+        .get(&RoleKey::Delegation(delegate.clone()))
+}
+
+/// Delegation of `delegate` if it covers `function` at the current time
+fn get_active_delegation(env: &Env, delegate: &Address, function: &Symbol) -> Option<Delegation> {
+    let delegation = get_delegation(env, delegate)?;
+    let now = env.ledger().timestamp();
+    if now >= delegation.valid_from && now < delegation.valid_until && delegation.functions.contains(function) {
+        Some(delegation)
+    } else {
+        None
+    }
+}
+
+// Admin transfer and renunciation
+
+/// Propose `new_admin` as the next contract admin (admin only).
+/// Replaces any pending transfer; `new_admin` must accept before `expires_at`.
+pub fn propose_admin_transfer(
+    env: &Env,
+    caller: &Address,
+    new_admin: &Address,
+    expires_at: u64,
+) -> Result<(), AuthError> {
+    caller.require_auth();
+    require_admin(env, caller)?;
+
+    if new_admin == caller || expires_at <= env.ledger().timestamp() {
+        return Err(AuthError::InvalidInput);
+    }
+
+    let transfer = PendingAdminTransfer {
+        current_admin: caller.clone(),
+        new_admin: new_admin.clone(),
+        expires_at,
+    };
+    env.storage()
+        .persistent()
+        .set(&RoleKey::PendingAdmin, &transfer);
+
+    env.events().publish(
+        (Symbol::new(env, "admin_transfer_proposed"), caller.clone()),
+        (new_admin.clone(), expires_at),
+    );
+
+    Ok(())
+}
+
+/// Withdraw a pending admin transfer (admin only)
+pub fn cancel_admin_transfer(env: &Env, caller: &Address) -> Result<(), AuthError> {
+    caller.require_auth();
+    require_admin(env, caller)?;
+
+    let transfer = get_pending_admin(env).ok_or(AuthError::NotFound)?;
+    env.storage()
+        .persistent()
+        .remove(&RoleKey::PendingAdmin);
+
+    env.events().publish(
+        (Symbol::new(env, "admin_transfer_cancelled"), caller.clone()),
+        transfer.new_admin,
+    );
+
+    Ok(())
+}
+
+/// Accept a pending admin transfer (proposed new admin only, before expiry).
+/// The previous admin loses the `Admin` role and keeps any other roles.
+pub fn accept_admin(env: &Env, new_admin: &Address) -> Result<(), AuthError> {
+    new_admin.require_auth();
+
+    let transfer = get_pending_admin(env).ok_or(AuthError::NotFound)?;
+    if &transfer.new_admin != new_admin {
+        return Err(AuthError::Unauthorized);
+    }
+    if env.ledger().timestamp() > transfer.expires_at {
+        return Err(AuthError::InvalidState);
+    }
+    // The proposer must still be the admin when the transfer is accepted
+    require_admin(env, &transfer.current_admin)?;
+
+    remove_role(env, &transfer.current_admin, Role::Admin);
+    env.storage()
+        .persistent()
+        .set(&RoleKey::ContractAdmin, new_admin);
+    add_role(env, new_admin, Role::Admin, None);
+    env.storage()
+        .persistent()
+        .remove(&RoleKey::PendingAdmin);
+    record_role_audit(
+        env,
+        RoleAuditAction::Transferred,
+        &transfer.current_admin,
+        new_admin,
+        Role::Admin,
+    );
+
+    env.events().publish(
+        (Symbol::new(env, "admin_transferred"), transfer.current_admin),
+        new_admin.clone(),
+    );
+
+    Ok(())
+}
+
+/// Get the pending admin transfer, if any (expired transfers are still returned)
+pub fn get_pending_admin(env: &Env) -> Option<PendingAdminTransfer> {
+    env.storage()
+        .persistent()
+        .get(&RoleKey::PendingAdmin)
+}
+
+/// Give up one of the caller's own roles. The contract admin must transfer `Admin` instead.
+pub fn renounce_role(env: &Env, caller: &Address, role: Role) -> Result<(), AuthError> {
+    caller.require_auth();
+
+    if role == Role::User || !has_role(env, caller, role.clone()) {
+        return Err(AuthError::RoleNotFound);
+    }
+    if role == Role::Admin && get_admin(env).as_ref() == Some(caller) {
+        return Err(AuthError::InvalidState);
+    }
+
+    remove_role(env, caller, role.clone());
+    record_role_audit(env, RoleAuditAction::Renounced, caller, caller, role.clone());
+
+    env.events().publish(
+        (Symbol::new(env, "role_renounced"), caller.clone()),
+        role,
+    );
+
+    Ok(())
+}
+
+// Multisig admin mode
+
+/// Get the multisig configuration, if the contract is in multisig admin mode
+pub fn get_multisig_config(env: &Env) -> Option<MultisigConfig> {
+    env.storage()
+        .persistent()
+        .get(&RoleKey::MultisigConfig)
+}
+
+/// Check if admin actions require multisig approval
+pub fn is_multisig_enabled(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .has(&RoleKey::MultisigConfig)
+}
+
+fn validate_multisig_config(signers: &Vec<Address>, threshold: u32) -> Result<(), AuthError> {
+    if threshold == 0 || threshold > signers.len() {
+        return Err(AuthError::InvalidInput);
+    }
+    for (i, signer) in signers.iter().enumerate() {
+        if signers.first_index_of(&signer) != Some(i as u32) {
+            return Err(AuthError::InvalidInput);
+        }
+    }
+    Ok(())
+}
+
+/// Switch from the single admin to an M-of-N multisig (single admin only, one-way).
+/// The single admin loses the `Admin` role and is cleared along with any pending
+/// transfer; afterwards every admin action needs `threshold` approvals.
+pub fn enable_multisig_admin(
+    env: &Env,
+    caller: &Address,
+    signers: Vec<Address>,
+    threshold: u32,
+) -> Result<(), AuthError> {
+    caller.require_auth();
+    require_admin(env, caller)?;
+
+    if is_multisig_enabled(env) {
+        return Err(AuthError::InvalidState);
+    }
+    validate_multisig_config(&signers, threshold)?;
+
+    remove_role(env, caller, Role::Admin);
+    record_role_audit(env, RoleAuditAction::Renounced, caller, caller, Role::Admin);
+    env.storage().persistent().remove(&RoleKey::ContractAdmin);
+    env.storage().persistent().remove(&RoleKey::PendingAdmin);
+    env.storage()
+        .persistent()
+        .set(&RoleKey::MultisigConfig, &MultisigConfig { signers, threshold });
+
+    Ok(())
+}
+
+/// Propose an admin action (signers only); the proposer's approval is recorded
+pub fn propose_admin_action(env: &Env, signer: &Address, action: AdminAction) -> Result<u64, AuthError> {
+    signer.require_auth();
+
+    let config = get_multisig_config(env).ok_or(AuthError::InvalidState)?;
+    if !config.signers.contains(signer) {
+        return Err(AuthError::Unauthorized);
+    }
+    if let AdminAction::RotateSigners(signers, threshold) = &action {
+        validate_multisig_config(signers, *threshold)?;
+    }
+
+    let id: u64 = env
+        .storage()
+        .persistent()
+        .get(&RoleKey::AdminProposalCounter)
+        .unwrap_or(0)
+        + 1;
+
+    let mut approvals = Vec::new(env);
+    approvals.push_back(signer.clone());
+
+    let proposal = AdminProposal {
+        id,
+        action,
+        proposer: signer.clone(),
+        approvals,
+        created_at: env.ledger().timestamp(),
+        executed: false,
+    };
+
+    env.storage()
+        .persistent()
+        .set(&RoleKey::AdminProposal(id), &proposal);
+    env.storage()
+        .persistent()
+        .set(&RoleKey::AdminProposalCounter, &id);
+
+    Ok(id)
+}
+
+/// Approve a pending admin action (signers only)
+pub fn approve_admin_action(env: &Env, signer: &Address, proposal_id: u64) -> Result<(), AuthError> {
+    signer.require_auth();
+
+    let config = get_multisig_config(env).ok_or(AuthError::InvalidState)?;
+    if !config.signers.contains(signer) {
+        return Err(AuthError::Unauthorized);
+    }
+
+    let mut proposal = get_admin_proposal(env, proposal_id).ok_or(AuthError::NotFound)?;
+    if proposal.executed {
+        return Err(AuthError::InvalidState);
+    }
+    if proposal.approvals.contains(signer) {
+        return Err(AuthError::AlreadyExists);
+    }
+
+    proposal.approvals.push_back(signer.clone());
+    env.storage()
+        .persistent()
+        .set(&RoleKey::AdminProposal(proposal_id), &proposal);
+
+    Ok(())
+}
+
+/// Execute an admin action once approvals from current signers reach the threshold.
+/// Approvals from signers rotated out in the meantime no longer count.
+pub fn execute_admin_action(env: &Env, signer: &Address, proposal_id: u64) -> Result<AdminAction, AuthError> {
+    signer.require_auth();
+
+    let config = get_multisig_config(env).ok_or(AuthError::InvalidState)?;
+    if !config.signers.contains(signer) {
+        return Err(AuthError::Unauthorized);
+    }
+
+    let mut proposal = get_admin_proposal(env, proposal_id).ok_or(AuthError::NotFound)?;
+    if proposal.executed {
+        return Err(AuthError::InvalidState);
+    }
+
+    let approvals = proposal
+        .approvals
+        .iter()
+        .filter(|approver| config.signers.contains(approver))
+        .count() as u32;
+    if approvals < config.threshold {
+        return Err(AuthError::Unauthorized);
+    }
+
+    match &proposal.action {
+        AdminAction::GrantRole(target, role, expires_at) => {
+            validate_grant(env, role, *expires_at)?;
+            add_role(env, target, role.clone(), *expires_at);
+            record_role_audit(env, RoleAuditAction::Granted, signer, target, role.clone());
+        }
+        AdminAction::RevokeRole(target, role) => {
+            remove_role(env, target, role.clone());
+            record_role_audit(env, RoleAuditAction::Revoked, signer, target, role.clone());
+        }
+        AdminAction::RegisterUnscopedTrustedContract(contract_address) => {
+            env.storage()
+                .persistent()
+                .set(&RoleKey::TrustedContract(contract_address.clone()), &true);
+        }
+        AdminAction::RegisterTrustedContractFor(contract_address, functions) => {
+            set_trusted_functions(env, contract_address, functions.clone())?;
+        }
+        AdminAction::UnregisterTrustedContract(contract_address) => {
+            remove_trusted_contract(env, contract_address);
+        }
+        AdminAction::RotateSigners(signers, threshold) => {
+            validate_multisig_config(signers, *threshold)?;
+            env.storage().persistent().set(
+                &RoleKey::MultisigConfig,
+                &MultisigConfig { signers: signers.clone(), threshold: *threshold },
+            );
+        }
+        AdminAction::Contract(action, args_hash) => {
+            let key = RoleKey::ApprovedAdminAction(action.clone(), args_hash.clone());
+            let approved: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+            env.storage().persistent().set(&key, &(approved + 1));
+        }
+    }
+
+    proposal.executed = true;
+    env.storage()
+        .persistent()
+        .set(&RoleKey::AdminProposal(proposal_id), &proposal);
+
+    Ok(proposal.action)
+}
+
+/// Get a multisig admin proposal
+pub fn get_admin_proposal(env: &Env, proposal_id: u64) -> Option<AdminProposal> {
+    env.storage()
+        .persistent()
+        .get(&RoleKey::AdminProposal(proposal_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{
+        contract, contractimpl, symbol_short,
+        testutils::{Address as _, Ledger},
+    };
+
+    #[contract]
+    struct TestContract;
+
+    #[contractimpl]
+    impl TestContract {}
+
+    fn setup() -> (Env, Address, Address, Vec<Address>) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(TestContract, ());
+        let admin = Address::generate(&env);
+        let mut signers = Vec::new(&env);
+        for _ in 0..3 {
+            signers.push_back(Address::generate(&env));
+        }
+        env.as_contract(&contract_id, || initialize_admin(&env, admin.clone()));
+        (env, contract_id, admin, signers)
+    }
+
+    #[test]
+    fn test_enable_multisig_retires_single_admin() {
+        let (env, contract_id, admin, signers) = setup();
+
+        let result = env.as_contract(&contract_id, || enable_multisig_admin(&env, &admin, signers.clone(), 4));
+        assert_eq!(result, Err(AuthError::InvalidInput));
+        env.as_contract(&contract_id, || enable_multisig_admin(&env, &admin, signers.clone(), 2)).unwrap();
+
+        env.as_contract(&contract_id, || {
+            assert!(is_multisig_enabled(&env));
+            assert_eq!(require_admin(&env, &admin), Err(AuthError::Unauthorized));
+            assert_eq!(
+                require_admin_action(&env, &admin, &symbol_short!("pause"), Vec::new(&env)),
+                Err(AuthError::Unauthorized)
+            );
+            assert_eq!(get_admin(&env), None);
+            assert!(is_admin_initialized(&env));
+        });
+    }
+
+    #[test]
+    fn test_multisig_action_executes_at_threshold() {
+        let (env, contract_id, admin, signers) = setup();
+        let target = Address::generate(&env);
+        let signer = signers.get(0).unwrap();
+        env.as_contract(&contract_id, || enable_multisig_admin(&env, &admin, signers.clone(), 2)).unwrap();
+
+        let action = AdminAction::GrantRole(target.clone(), Role::ClaimProcessor, None);
+        let id = env.as_contract(&contract_id, || propose_admin_action(&env, &signer, action)).unwrap();
+
+        let result = env.as_contract(&contract_id, || execute_admin_action(&env, &signer, id));
+        assert_eq!(result, Err(AuthError::Unauthorized));
+        let result = env.as_contract(&contract_id, || approve_admin_action(&env, &signer, id));
+        assert_eq!(result, Err(AuthError::AlreadyExists));
+
+        env.as_contract(&contract_id, || approve_admin_action(&env, &signers.get(1).unwrap(), id)).unwrap();
+        env.as_contract(&contract_id, || execute_admin_action(&env, &signer, id)).unwrap();
+
+        assert_eq!(env.as_contract(&contract_id, || get_role(&env, &target)), Role::ClaimProcessor);
+        let result = env.as_contract(&contract_id, || execute_admin_action(&env, &signer, id));
+        assert_eq!(result, Err(AuthError::InvalidState));
+    }
+
+    #[test]
+    fn test_contract_action_approval_is_consumed_once() {
+        let (env, contract_id, admin, signers) = setup();
+        let signer = signers.get(0).unwrap();
+        env.as_contract(&contract_id, || enable_multisig_admin(&env, &admin, signers.clone(), 1)).unwrap();
+
+        let pause = symbol_short!("pause");
+        let args: Vec<Val> = (1_000u64,).into_val(&env);
+        let action = AdminAction::Contract(pause.clone(), admin_action_hash(&env, &pause, &args));
+        let id = env.as_contract(&contract_id, || propose_admin_action(&env, &signer, action)).unwrap();
+        env.as_contract(&contract_id, || execute_admin_action(&env, &signer, id)).unwrap();
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(
+                require_admin_action(&env, &signer, &symbol_short!("unpause"), args.clone()),
+                Err(AuthError::Unauthorized)
+            );
+            // The approval covers the approved arguments only
+            assert_eq!(
+                require_admin_action(&env, &signer, &pause, (2_000u64,).into_val(&env)),
+                Err(AuthError::Unauthorized)
+            );
+            assert_eq!(require_admin_action(&env, &signer, &pause, args.clone()), Ok(()));
+            assert_eq!(
+                require_admin_action(&env, &signer, &pause, args.clone()),
+                Err(AuthError::Unauthorized)
+            );
+        });
+    }
+
+    #[test]
+    fn test_grant_role_in_multisig_mode_needs_approved_action() {
+        let (env, contract_id, admin, signers) = setup();
+        let processor = Address::generate(&env);
+        let signer = signers.get(0).unwrap();
+        env.as_contract(&contract_id, || enable_multisig_admin(&env, &admin, signers.clone(), 1)).unwrap();
+
+        let result = env.as_contract(&contract_id, || {
+            grant_role(&env, &signer, &processor, Role::ClaimProcessor, None)
+        });
+        assert_eq!(result, Err(AuthError::Unauthorized));
+
+        let function = Symbol::new(&env, "grant_role");
+        let args: Vec<Val> = (processor.clone(), Role::ClaimProcessor, None::<u64>).into_val(&env);
+        let action = AdminAction::Contract(function.clone(), admin_action_hash(&env, &function, &args));
+        let id = env.as_contract(&contract_id, || propose_admin_action(&env, &signer, action)).unwrap();
+        env.as_contract(&contract_id, || execute_admin_action(&env, &signer, id)).unwrap();
+
+        env.as_contract(&contract_id, || {
+            // A different role is not covered by the approval
+            assert_eq!(
+                grant_role(&env, &signer, &processor, Role::Admin, None),
+                Err(AuthError::Unauthorized)
+            );
+            grant_role(&env, &signer, &processor, Role::ClaimProcessor, None).unwrap();
+            assert_eq!(get_role(&env, &processor), Role::ClaimProcessor);
+        });
+    }
+
+    #[test]
+    fn test_signer_rotation_requires_threshold() {
+        let (env, contract_id, admin, signers) = setup();
+        let newcomer = Address::generate(&env);
+        let signer = signers.get(0).unwrap();
+        env.as_contract(&contract_id, || enable_multisig_admin(&env, &admin, signers.clone(), 2)).unwrap();
+
+        let mut rotated = Vec::new(&env);
+        rotated.push_back(signer.clone());
+        rotated.push_back(newcomer.clone());
+
+        let action = AdminAction::RotateSigners(rotated.clone(), 2);
+        let id = env.as_contract(&contract_id, || propose_admin_action(&env, &signer, action)).unwrap();
+        let result = env.as_contract(&contract_id, || execute_admin_action(&env, &signer, id));
+        assert_eq!(result, Err(AuthError::Unauthorized));
+
+        env.as_contract(&contract_id, || approve_admin_action(&env, &signers.get(2).unwrap(), id)).unwrap();
+        env.as_contract(&contract_id, || execute_admin_action(&env, &signer, id)).unwrap();
+
+        let config = env.as_contract(&contract_id, || get_multisig_config(&env)).unwrap();
+        assert_eq!(config.signers, rotated);
+
+        // Signers rotated out can no longer propose
+        let action = AdminAction::RevokeRole(newcomer.clone(), Role::Admin);
+        let result = env.as_contract(&contract_id, || propose_admin_action(&env, &signers.get(2).unwrap(), action));
+        assert_eq!(result, Err(AuthError::Unauthorized));
+    }
+
+    #[test]
+    fn test_admin_transfer_requires_acceptance() {
+        let (env, contract_id, admin, _) = setup();
+        let new_admin = Address::generate(&env);
+        let outsider = Address::generate(&env);
+
+        env.as_contract(&contract_id, || propose_admin_transfer(&env, &admin, &new_admin, 1_000)).unwrap();
+        assert_eq!(env.as_contract(&contract_id, || get_admin(&env)), Some(admin.clone()));
+
+        let result = env.as_contract(&contract_id, || accept_admin(&env, &outsider));
+        assert_eq!(result, Err(AuthError::Unauthorized));
+
+        env.as_contract(&contract_id, || accept_admin(&env, &new_admin)).unwrap();
+        env.as_contract(&contract_id, || {
+            assert_eq!(get_admin(&env), Some(new_admin.clone()));
+            assert_eq!(get_role(&env, &new_admin), Role::Admin);
+            assert_eq!(get_role(&env, &admin), Role::User);
+            assert_eq!(get_pending_admin(&env), None);
+        });
+    }
+
+    #[test]
+    fn test_admin_transfer_expires() {
+        let (env, contract_id, admin, _) = setup();
+        let new_admin = Address::generate(&env);
+
+        env.as_contract(&contract_id, || propose_admin_transfer(&env, &admin, &new_admin, 1_000)).unwrap();
+        env.ledger().set_timestamp(1_001);
+
+        let result = env.as_contract(&contract_id, || accept_admin(&env, &new_admin));
+        assert_eq!(result, Err(AuthError::InvalidState));
+        assert_eq!(env.as_contract(&contract_id, || get_admin(&env)), Some(admin));
+    }
+
+    #[test]
+    fn test_renounce_role() {
+        let (env, contract_id, admin, _) = setup();
+        let processor = Address::generate(&env);
+
+        env.as_contract(&contract_id, || grant_role(&env, &admin, &processor, Role::ClaimProcessor, None)).unwrap();
+        env.as_contract(&contract_id, || renounce_role(&env, &processor, Role::ClaimProcessor)).unwrap();
+        assert_eq!(env.as_contract(&contract_id, || get_role(&env, &processor)), Role::User);
+
+        let result = env.as_contract(&contract_id, || renounce_role(&env, &processor, Role::ClaimProcessor));
+        assert_eq!(result, Err(AuthError::RoleNotFound));
+
+        // The admin has to hand the role over instead of abandoning the contract
+        let result = env.as_contract(&contract_id, || renounce_role(&env, &admin, Role::Admin));
+        assert_eq!(result, Err(AuthError::InvalidState));
+    }
+
+    #[test]
+    fn test_address_holds_multiple_roles() {
+        let (env, contract_id, admin, _) = setup();
+        let operator = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            grant_role(&env, &admin, &operator, Role::PolicyManager, None).unwrap();
+            grant_role(&env, &admin, &operator, Role::ClaimProcessor, None).unwrap();
+
+            assert!(has_role(&env, &operator, Role::PolicyManager));
+            assert!(has_role(&env, &operator, Role::ClaimProcessor));
+            assert!(!has_role(&env, &operator, Role::Governance));
+            assert_eq!(get_role(&env, &operator), Role::PolicyManager);
+            assert!(require_claim_processing(&env, &operator).is_ok());
+            assert!(require_any_role(&env, &operator, &[Role::Governance, Role::ClaimProcessor]).is_ok());
+            assert_eq!(grant_role(&env, &admin, &operator, Role::User, None), Err(AuthError::InvalidRole));
+
+            revoke_role(&env, &admin, &operator, Role::PolicyManager).unwrap();
+            assert!(!has_role(&env, &operator, Role::PolicyManager));
+            assert!(has_role(&env, &operator, Role::ClaimProcessor));
+            assert_eq!(
+                revoke_role(&env, &admin, &operator, Role::PolicyManager),
+                Err(AuthError::RoleNotFound)
+            );
+            assert_eq!(
+                revoke_role(&env, &admin, &admin, Role::Admin),
+                Err(AuthError::Unauthorized)
+            );
+        });
+    }
+
+    #[test]
+    fn test_legacy_single_role_entry_is_migrated() {
+        let (env, contract_id, admin, _) = setup();
+        let manager = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&RoleKey::UserRole(manager.clone()), &Role::RiskPoolManager);
+            assert!(has_role(&env, &manager, Role::RiskPoolManager));
+
+            grant_role(&env, &admin, &manager, Role::Governance, None).unwrap();
+            assert!(!env.storage().persistent().has(&RoleKey::UserRole(manager.clone())));
+            assert_eq!(get_roles(&env, &manager).len(), 2);
+            assert!(!migrate_role_entry(&env, &manager));
+        });
+    }
+
+    #[test]
+    fn test_time_bounded_grant_expires() {
+        let (env, contract_id, admin, _) = setup();
+        let contractor = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            grant_role(&env, &admin, &contractor, Role::ClaimProcessor, Some(1_000)).unwrap();
+            grant_role(&env, &admin, &contractor, Role::PolicyManager, None).unwrap();
+            assert_eq!(
+                grant_role(&env, &admin, &contractor, Role::Admin, Some(1_000)),
+                Err(AuthError::InvalidInput)
+            );
+
+            let grants = get_role_grants(&env, &contractor);
+            assert_eq!(grants.len(), 2);
+            assert!(grants.contains(RoleGrant { role: Role::ClaimProcessor, expires_at: Some(1_000) }));
+            assert!(require_role(&env, &contractor, Role::ClaimProcessor).is_ok());
+        });
+
+        env.ledger().set_timestamp(1_000);
+        env.as_contract(&contract_id, || {
+            assert!(!has_role(&env, &contractor, Role::ClaimProcessor));
+            assert_eq!(require_role(&env, &contractor, Role::ClaimProcessor), Err(AuthError::Unauthorized));
+            assert!(has_role(&env, &contractor, Role::PolicyManager));
+            assert_eq!(get_role_grants(&env, &contractor).len(), 1);
+            assert_eq!(
+                grant_role(&env, &admin, &contractor, Role::ClaimProcessor, Some(1_000)),
+                Err(AuthError::InvalidInput)
+            );
+
+            // Renewing without an expiry makes the grant permanent
+            grant_role(&env, &admin, &contractor, Role::ClaimProcessor, None).unwrap();
+            assert!(has_role(&env, &contractor, Role::ClaimProcessor));
+        });
+    }
+
+    #[test]
+    fn test_role_members_and_audit_log() {
+        let (env, contract_id, admin, _) = setup();
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            grant_role(&env, &admin, &first, Role::ClaimProcessor, None).unwrap();
+            grant_role(&env, &admin, &second, Role::ClaimProcessor, Some(1_000)).unwrap();
+            grant_role(&env, &admin, &first, Role::ClaimProcessor, None).unwrap();
+
+            let members = get_role_members(&env, Role::ClaimProcessor, 0, 0);
+            assert_eq!(members.len(), 2);
+            assert_eq!(get_role_members(&env, Role::ClaimProcessor, 1, 1).get(0), Some(second.clone()));
+
+            revoke_role(&env, &admin, &first, Role::ClaimProcessor).unwrap();
+            assert_eq!(get_role_members(&env, Role::ClaimProcessor, 0, 10).get(0), Some(second.clone()));
+        });
+
+        env.ledger().set_timestamp(1_000);
+        env.as_contract(&contract_id, || {
+            assert!(get_role_members(&env, Role::ClaimProcessor, 0, 10).is_empty());
+            assert_eq!(get_role_members(&env, Role::Admin, 0, 10).get(0), Some(admin.clone()));
+
+            // initialize, three grants and a revocation
+            let log = get_role_audit_log(&env, 0, 0);
+            assert_eq!(log.len(), 5);
+            let entry = log.get(4).unwrap();
+            assert_eq!(entry.id, 4);
+            assert_eq!(entry.action, RoleAuditAction::Revoked);
+            assert_eq!(entry.actor, admin);
+            assert_eq!(entry.target, first);
+            assert_eq!(entry.role, Role::ClaimProcessor);
+
+            let page = get_role_audit_log(&env, 3, 10);
+            assert_eq!(page.len(), 2);
+            assert_eq!(page.get(0).unwrap().id, 3);
+            assert!(get_role_audit_log(&env, 5, 10).is_empty());
+        });
+    }
+
+    #[test]
+    fn test_trusted_contract_scoped_to_functions() {
+        let (env, contract_id, admin, _) = setup();
+        let claims = Address::generate(&env);
+        let legacy = Address::generate(&env);
+        let reserve = Symbol::new(&env, "reserve_liquidity");
+        let admin_hook = Symbol::new(&env, "set_fee");
+
+        env.as_contract(&contract_id, || {
+            let mut functions = Vec::new(&env);
+            functions.push_back(reserve.clone());
+            register_trusted_contract_for(&env, &admin, &claims, functions).unwrap();
+            register_unscoped_trust(&env, &admin, &legacy).unwrap();
+
+            assert_eq!(require_trusted_contract_for(&env, &claims, &reserve), Ok(()));
+            assert_eq!(
+                require_trusted_contract_for(&env, &claims, &admin_hook),
+                Err(AuthError::NotTrustedContract)
+            );
+            assert_eq!(require_trusted_contract_for(&env, &legacy, &admin_hook), Ok(()));
+            assert_eq!(
+                register_trusted_contract_for(&env, &admin, &claims, Vec::new(&env)),
+                Err(AuthError::InvalidInput)
+            );
+
+            unregister_trusted_contract(&env, &admin, &claims).unwrap();
+            assert_eq!(get_trusted_functions(&env, &claims), None);
+            assert_eq!(
+                require_trusted_contract_for(&env, &claims, &reserve),
+                Err(AuthError::NotTrustedContract)
+            );
+        });
+    }
+
+    #[test]
+    fn test_delegate_acts_for_principal() {
+        let (env, contract_id, admin, _) = setup();
+        let processor = Address::generate(&env);
+        let session_key = Address::generate(&env);
+        let approve = Symbol::new(&env, "approve_claim");
+        let settle = Symbol::new(&env, "settle_claim");
+
+        env.as_contract(&contract_id, || grant_role(&env, &admin, &processor, Role::ClaimProcessor, None)).unwrap();
+        let mut functions = Vec::new(&env);
+        functions.push_back(approve.clone());
+        env.as_contract(&contract_id, || {
+            delegate_operator(&env, &processor, &session_key, functions.clone(), 100, 1_000)
+        })
+        .unwrap();
+
+        // Not yet valid
+        let result = env.as_contract(&contract_id, || {
+            verify_and_require_role(&env, &session_key, Role::ClaimProcessor, &approve)
+        });
+        assert_eq!(result, Err(AuthError::Unauthorized));
+
+        env.ledger().set_timestamp(100);
+        let principal = env.as_contract(&contract_id, || {
+            verify_and_require_role(&env, &session_key, Role::ClaimProcessor, &approve)
+        });
+        assert_eq!(principal, Ok(processor.clone()));
+        let principal = env.as_contract(&contract_id, || {
+            verify_and_check_permission(&env, &session_key, &approve, Role::can_process_claims)
+        });
+        assert_eq!(principal, Ok(processor.clone()));
+        let principal = env.as_contract(&contract_id, || {
+            verify_and_require_role(&env, &processor, Role::ClaimProcessor, &settle)
+        });
+        assert_eq!(principal, Ok(processor.clone()));
+
+        // Outside the delegated function subset
+        let result = env.as_contract(&contract_id, || {
+            verify_and_require_role(&env, &session_key, Role::ClaimProcessor, &settle)
+        });
+        assert_eq!(result, Err(AuthError::Unauthorized));
+
+        // A delegate never outlives the principal's role
+        env.as_contract(&contract_id, || revoke_role(&env, &admin, &processor, Role::ClaimProcessor)).unwrap();
+        let result = env.as_contract(&contract_id, || {
+            verify_and_require_role(&env, &session_key, Role::ClaimProcessor, &approve)
+        });
+        assert_eq!(result, Err(AuthError::Unauthorized));
+    }
+
+    #[test]
+    fn test_delegation_expires_and_can_be_revoked() {
+        let (env, contract_id, admin, _) = setup();
+        let processor = Address::generate(&env);
+        let other = Address::generate(&env);
+        let session_key = Address::generate(&env);
+        let approve = Symbol::new(&env, "approve_claim");
+        let mut functions = Vec::new(&env);
+        functions.push_back(approve.clone());
+
+        env.as_contract(&contract_id, || grant_role(&env, &admin, &processor, Role::ClaimProcessor, None)).unwrap();
+        env.as_contract(&contract_id, || grant_role(&env, &admin, &other, Role::ClaimProcessor, None)).unwrap();
+
+        let result = env.as_contract(&contract_id, || {
+            delegate_operator(&env, &session_key, &other, functions.clone(), 0, 1_000)
+        });
+        assert_eq!(result, Err(AuthError::RoleNotFound));
+        env.as_contract(&contract_id, || {
+            delegate_operator(&env, &processor, &session_key, functions.clone(), 0, 1_000)
+        })
+        .unwrap();
+        let result = env.as_contract(&contract_id, || {
+            delegate_operator(&env, &other, &session_key, functions.clone(), 0, 1_000)
+        });
+        assert_eq!(result, Err(AuthError::AlreadyExists));
+
+        env.ledger().set_timestamp(1_000);
+        let result = env.as_contract(&contract_id, || {
+            verify_and_require_role(&env, &session_key, Role::ClaimProcessor, &approve)
+        });
+        assert_eq!(result, Err(AuthError::Unauthorized));
+
+        let result = env.as_contract(&contract_id, || revoke_delegation(&env, &other, &session_key));
+        assert_eq!(result, Err(AuthError::Unauthorized));
+        env.as_contract(&contract_id, || revoke_delegation(&env, &processor, &session_key)).unwrap();
+        assert_eq!(env.as_contract(&contract_id, || get_delegation(&env, &session_key)), None);
     }
 }
@@ -7,7 +7,7 @@ use soroban_sdk::{
 // Import the Policy contract interface to verify ownership and coverage
 // NOTE: policy contract client import omitted in this workspace build; it requires
 // a pre-built wasm artifact at build-time.
-use soroban_sdk::{contract, contractimpl, contracterror, contracttype, Address, Env, Symbol, symbol_short, IntoVal, Vec};
+use soroban_sdk::{contract, contractimpl, contracterror, contracttype, Address, BytesN, Env, Symbol, symbol_short, IntoVal, Vec};
 
 // Import the Policy contract interface to verify ownership and coverage
 #[cfg(not(test))]
@@ -41,10 +41,15 @@ mod policy_contract {
 
 // Import shared types and authorization from the common library
 use insurance_contracts::authorization::{
-    get_role, initialize_admin, register_trusted_contract, require_admin, require_claim_processing,
-    require_trusted_contract, Role,
+    get_role, initialize_admin, register_trusted_contract_for, require_admin_action,
+    require_trusted_contract_for, verify_and_check_permission, AdminAction, AdminProposal,
+    Delegation, MultisigConfig, PendingAdminTransfer, Role, RoleAuditEntry, RoleGrant,
 };
 use insurance_contracts::types::ClaimStatus;
+use insurance_contracts::upgradeable::{
+    default_migration_hook, UpgradeableContract, VersionManager, VersionTransition,
+    VersioningError,
+};
 
 // Import invariants and safety assertions
 use insurance_invariants::{InvariantError, ProtocolInvariants};
@@ -76,6 +81,8 @@ const CLM_ORA: Symbol = symbol_short!("CLM_ORA");
 // New storage keys for claim indexing
 const CLAIM_LIST: Symbol = symbol_short!("CLM_LST");
 const CLAIM_COUNTER: Symbol = symbol_short!("CLM_CNT");
+const GOVERNANCE_CONTRACT: Symbol = symbol_short!("GOV_CON");
+const SETTLEMENT_FREEZE: Symbol = symbol_short!("SETL_FRZ");
 
 /// Maximum number of claims to return in a single paginated request.
 const MAX_PAGINATION_LIMIT: u32 = 50;
@@ -90,6 +97,7 @@ pub enum ContractError {
     NotFound = 5,
     AlreadyExists = 6,
     InvalidState = 7,
+    SettlementsFrozen = 8,
     NotInitialized = 9,
     AlreadyInitialized = 10,
     // Oracle errors
@@ -123,6 +131,16 @@ impl From<insurance_contracts::authorization::AuthError> for ContractError {
             insurance_contracts::authorization::AuthError::NotTrustedContract => {
                 ContractError::NotTrustedContract
             }
+            insurance_contracts::authorization::AuthError::InvalidInput => {
+                ContractError::InvalidInput
+            }
+            insurance_contracts::authorization::AuthError::NotFound => ContractError::NotFound,
+            insurance_contracts::authorization::AuthError::AlreadyExists => {
+                ContractError::AlreadyExists
+            }
+            insurance_contracts::authorization::AuthError::InvalidState => {
+                ContractError::InvalidState
+            }
         }
     }
 }
@@ -168,6 +186,20 @@ pub struct PaginatedClaimsResult {
     pub total_count: u32,
 }
 
+fn map_versioning_error(e: VersioningError) -> ContractError {
+    match e {
+        VersioningError::NotInitialized => ContractError::NotInitialized,
+        VersioningError::MigrationInProgress => ContractError::Paused,
+        VersioningError::UnauthorizedUpgrade => ContractError::Unauthorized,
+        VersioningError::InvalidVersionNumber => ContractError::InvalidInput,
+        VersioningError::VersionMismatch
+        | VersioningError::MigrationFailed
+        | VersioningError::MigrationHookFailed
+        | VersioningError::SchemaValidationFailed
+        | VersioningError::RollbackFailed => ContractError::InvalidState,
+    }
+}
+
 fn validate_address(_env: &Env, _address: &Address) -> Result<(), ContractError> {
     Ok(())
 }
@@ -180,6 +212,11 @@ fn set_paused(env: &Env, paused: bool) {
     env.storage().persistent().set(&PAUSED, &paused);
 }
 
+/// Settlements stay frozen until this timestamp (0 when no freeze was ever set)
+fn settlement_freeze_until(env: &Env) -> u64 {
+    env.storage().persistent().get(&SETTLEMENT_FREEZE).unwrap_or(0)
+}
+
 /// I3: Validate claim state transition
 /// Maps valid state transitions to ensure claim lifecycle integrity
 fn is_valid_state_transition(current: ClaimStatus, next: ClaimStatus) -> bool {
@@ -222,7 +259,7 @@ impl ClaimsContract {
         risk_pool: Address,
     ) -> Result<(), ContractError> {
         // Check if already initialized
-        if insurance_contracts::authorization::get_admin(&env).is_some() {
+        if insurance_contracts::authorization::is_admin_initialized(&env) {
             return Err(ContractError::AlreadyInitialized);
         }
 
@@ -234,9 +271,14 @@ impl ClaimsContract {
         admin.require_auth();
         initialize_admin(&env, admin.clone());
 
-        // Register policy and risk pool contracts as trusted for cross-contract calls
-        register_trusted_contract(&env, &admin, &policy_contract)?;
-        register_trusted_contract(&env, &admin, &risk_pool)?;
+        // Trust the policy and risk pool contracts for the entry points this contract calls on them
+        let mut policy_functions = Vec::new(&env);
+        policy_functions.push_back(Symbol::new(&env, "get_policy"));
+        register_trusted_contract_for(&env, &admin, &policy_contract, policy_functions)?;
+        let mut risk_pool_functions = Vec::new(&env);
+        risk_pool_functions.push_back(Symbol::new(&env, "reserve_liquidity"));
+        risk_pool_functions.push_back(Symbol::new(&env, "payout_reserved_claim"));
+        register_trusted_contract_for(&env, &admin, &risk_pool, risk_pool_functions)?;
 
         // Store contract configuration
         env.storage().persistent().set(&CONFIG, &(policy_contract, risk_pool));
@@ -256,12 +298,20 @@ impl ClaimsContract {
     ) -> Result<(), ContractError> {
         // Verify identity and require admin permission
         admin.require_auth();
-        require_admin(&env, &admin)?;
+        require_admin_action(
+            &env,
+            &admin,
+            &Symbol::new(&env, "set_oracle_config"),
+            (oracle_contract.clone(), require_oracle_validation, min_oracle_submissions).into_val(&env),
+        )?;
 
         validate_address(&env, &oracle_contract)?;
 
-        // Register oracle contract as trusted for cross-contract calls
-        register_trusted_contract(&env, &admin, &oracle_contract)?;
+        // Trust the oracle contract for the entry points this contract calls on it
+        let mut oracle_functions = Vec::new(&env);
+        oracle_functions.push_back(Symbol::new(&env, "get_submission_count"));
+        oracle_functions.push_back(Symbol::new(&env, "resolve_oracle_data"));
+        register_trusted_contract_for(&env, &admin, &oracle_contract, oracle_functions)?;
 
         let config = OracleValidationConfig {
             oracle_contract: oracle_contract.clone(),
@@ -303,12 +353,13 @@ impl ClaimsContract {
         }
 
         // Verify oracle contract is trusted before making cross-contract calls
-        require_trusted_contract(&env, &oracle_config.oracle_contract)?;
+        let get_submission_count = Symbol::new(&env, "get_submission_count");
+        require_trusted_contract_for(&env, &oracle_config.oracle_contract, &get_submission_count)?;
 
         // Get oracle submission count using invoke_contract
         let submission_count: u32 = env.invoke_contract(
             &oracle_config.oracle_contract,
-            &Symbol::new(&env, "get_submission_count"),
+            &get_submission_count,
             (oracle_data_id,).into_val(&env),
         );
 
@@ -318,9 +369,11 @@ impl ClaimsContract {
         }
 
         // Attempt to resolve oracle data - this will validate consensus and staleness
+        let resolve_oracle_data = Symbol::new(&env, "resolve_oracle_data");
+        require_trusted_contract_for(&env, &oracle_config.oracle_contract, &resolve_oracle_data)?;
         let _oracle_data: (i128, u32, u32, u64) = env.invoke_contract(
             &oracle_config.oracle_contract,
-            &Symbol::new(&env, "resolve_oracle_data"),
+            &resolve_oracle_data,
             (oracle_data_id,).into_val(&env),
         );
 
@@ -457,9 +510,14 @@ impl ClaimsContract {
         claim_id: u64,
         oracle_data_id: Option<u64>,
     ) -> Result<(), ContractError> {
-        // Verify identity and require claim processing permission
-        processor.require_auth();
-        require_claim_processing(&env, &processor)?;
+        // Verify identity and require claim processing permission; a delegated
+        // operator key may sign for the processor
+        verify_and_check_permission(
+            &env,
+            &processor,
+            &Symbol::new(&env, "approve_claim"),
+            Role::can_process_claims,
+        )?;
 
         let mut claim: (u64, Address, i128, ClaimStatus, u64) = env
             .storage()
@@ -485,12 +543,13 @@ impl ClaimsContract {
             if oracle_config.require_oracle_validation {
                 if let Some(oracle_id) = oracle_data_id {
                     // Verify oracle contract is trusted
-                    require_trusted_contract(&env, &oracle_config.oracle_contract)?;
+                    let get_submission_count = Symbol::new(&env, "get_submission_count");
+                    require_trusted_contract_for(&env, &oracle_config.oracle_contract, &get_submission_count)?;
 
                     // Validate using oracle data (store oracle data ID)
                     let _submission_count: u32 = env.invoke_contract(
                         &oracle_config.oracle_contract,
-                        &Symbol::new(&env, "get_submission_count"),
+                        &get_submission_count,
                         (oracle_id,).into_val(&env),
                     );
 
@@ -507,11 +566,12 @@ impl ClaimsContract {
         let risk_pool_contract = config.1.clone();
 
         // Verify risk pool is a trusted contract before invoking
-        require_trusted_contract(&env, &risk_pool_contract)?;
+        let reserve_liquidity = Symbol::new(&env, "reserve_liquidity");
+        require_trusted_contract_for(&env, &risk_pool_contract, &reserve_liquidity)?;
 
         env.invoke_contract::<()>(
             &risk_pool_contract,
-            &Symbol::new(&env, "reserve_liquidity"),
+            &reserve_liquidity,
             (claim_id, claim.2).into_val(&env),
         );
 
@@ -526,9 +586,14 @@ impl ClaimsContract {
     }
 
     pub fn start_review(env: Env, processor: Address, claim_id: u64) -> Result<(), ContractError> {
-        // Verify identity and require claim processing permission
-        processor.require_auth();
-        require_claim_processing(&env, &processor)?;
+        // Verify identity and require claim processing permission; a delegated
+        // operator key may sign for the processor
+        verify_and_check_permission(
+            &env,
+            &processor,
+            &Symbol::new(&env, "start_review"),
+            Role::can_process_claims,
+        )?;
 
         let mut claim: (u64, Address, i128, ClaimStatus, u64) = env
             .storage()
@@ -553,9 +618,14 @@ impl ClaimsContract {
     }
 
     pub fn reject_claim(env: Env, processor: Address, claim_id: u64) -> Result<(), ContractError> {
-        // Verify identity and require claim processing permission
-        processor.require_auth();
-        require_claim_processing(&env, &processor)?;
+        // Verify identity and require claim processing permission; a delegated
+        // operator key may sign for the processor
+        verify_and_check_permission(
+            &env,
+            &processor,
+            &Symbol::new(&env, "reject_claim"),
+            Role::can_process_claims,
+        )?;
 
         let mut claim: (u64, Address, i128, ClaimStatus, u64) = env
             .storage()
@@ -580,9 +650,18 @@ impl ClaimsContract {
     }
 
     pub fn settle_claim(env: Env, processor: Address, claim_id: u64) -> Result<(), ContractError> {
-        // Verify identity and require claim processing permission
-        processor.require_auth();
-        require_claim_processing(&env, &processor)?;
+        // Verify identity and require claim processing permission; a delegated
+        // operator key may sign for the processor
+        verify_and_check_permission(
+            &env,
+            &processor,
+            &Symbol::new(&env, "settle_claim"),
+            Role::can_process_claims,
+        )?;
+
+        if env.ledger().timestamp() < settlement_freeze_until(&env) {
+            return Err(ContractError::SettlementsFrozen);
+        }
 
         let mut claim: (u64, Address, i128, ClaimStatus, u64) = env
             .storage()
@@ -606,12 +685,13 @@ impl ClaimsContract {
         let risk_pool_contract = config.1.clone();
 
         // Verify risk pool is a trusted contract before invoking
-        require_trusted_contract(&env, &risk_pool_contract)?;
+        let payout_reserved_claim = Symbol::new(&env, "payout_reserved_claim");
+        require_trusted_contract_for(&env, &risk_pool_contract, &payout_reserved_claim)?;
 
         // Call risk pool to payout the claim amount
         env.invoke_contract::<()>(
             &risk_pool_contract,
-            &Symbol::new(&env, "payout_reserved_claim"),
+            &payout_reserved_claim,
             (claim_id, claim.1.clone()).into_val(&env),
         );
 
@@ -626,10 +706,260 @@ impl ClaimsContract {
         Ok(())
     }
 
+    /// Proposes `new_admin` as the next admin, to be accepted before `expires_at` (admin only).
+    pub fn propose_admin_transfer(
+        env: Env,
+        admin: Address,
+        new_admin: Address,
+        expires_at: u64,
+    ) -> Result<(), ContractError> {
+        validate_address(&env, &new_admin)?;
+        insurance_contracts::authorization::propose_admin_transfer(&env, &admin, &new_admin, expires_at)?;
+        Ok(())
+    }
+
+    /// Withdraws the pending admin transfer (admin only).
+    pub fn cancel_admin_transfer(env: Env, admin: Address) -> Result<(), ContractError> {
+        insurance_contracts::authorization::cancel_admin_transfer(&env, &admin)?;
+        Ok(())
+    }
+
+    /// Completes a pending admin transfer (proposed admin only).
+    pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), ContractError> {
+        insurance_contracts::authorization::accept_admin(&env, &new_admin)?;
+        Ok(())
+    }
+
+    pub fn get_pending_admin(env: Env) -> Option<PendingAdminTransfer> {
+        insurance_contracts::authorization::get_pending_admin(&env)
+    }
+
+    /// Gives up one of the caller's own roles. The admin must transfer `Admin` instead.
+    pub fn renounce_role(env: Env, caller: Address, role: Role) -> Result<(), ContractError> {
+        insurance_contracts::authorization::renounce_role(&env, &caller, role)?;
+        Ok(())
+    }
+
+    /// Lets `delegate` sign `functions` (e.g. `start_review`, `approve_claim`) on behalf of
+    /// the calling role holder between `valid_from` and `valid_until`.
+    pub fn delegate_operator(
+        env: Env,
+        principal: Address,
+        delegate: Address,
+        functions: Vec<Symbol>,
+        valid_from: u64,
+        valid_until: u64,
+    ) -> Result<(), ContractError> {
+        validate_address(&env, &delegate)?;
+        insurance_contracts::authorization::delegate_operator(
+            &env,
+            &principal,
+            &delegate,
+            functions,
+            valid_from,
+            valid_until,
+        )?;
+        Ok(())
+    }
+
+    /// Withdraws a delegation before it lapses (principal only).
+    pub fn revoke_delegation(env: Env, principal: Address, delegate: Address) -> Result<(), ContractError> {
+        insurance_contracts::authorization::revoke_delegation(&env, &principal, &delegate)?;
+        Ok(())
+    }
+
+    pub fn get_delegation(env: Env, delegate: Address) -> Option<Delegation> {
+        insurance_contracts::authorization::get_delegation(&env, &delegate)
+    }
+
+    /// Switches this contract from the single admin to an M-of-N multisig admin (admin only).
+    pub fn enable_multisig_admin(
+        env: Env,
+        admin: Address,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<(), ContractError> {
+        insurance_contracts::authorization::enable_multisig_admin(&env, &admin, signers, threshold)?;
+
+        env.events().publish((Symbol::new(&env, "multisig_enabled"), ()), (admin, threshold));
+
+        Ok(())
+    }
+
+    /// Proposes an admin action to the multisig (signers only).
+    pub fn propose_admin_action(
+        env: Env,
+        signer: Address,
+        action: AdminAction,
+    ) -> Result<u64, ContractError> {
+        let proposal_id =
+            insurance_contracts::authorization::propose_admin_action(&env, &signer, action)?;
+
+        env.events().publish((Symbol::new(&env, "admin_action_proposed"), proposal_id), signer);
+
+        Ok(proposal_id)
+    }
+
+    /// Approves a pending multisig admin action (signers only).
+    pub fn approve_admin_action(
+        env: Env,
+        signer: Address,
+        proposal_id: u64,
+    ) -> Result<(), ContractError> {
+        insurance_contracts::authorization::approve_admin_action(&env, &signer, proposal_id)?;
+
+        env.events().publish((Symbol::new(&env, "admin_action_approved"), proposal_id), signer);
+
+        Ok(())
+    }
+
+    /// Executes a multisig admin action once the approval threshold is reached (signers only).
+    pub fn execute_admin_action(
+        env: Env,
+        signer: Address,
+        proposal_id: u64,
+    ) -> Result<(), ContractError> {
+        insurance_contracts::authorization::execute_admin_action(&env, &signer, proposal_id)?;
+
+        env.events().publish((Symbol::new(&env, "admin_action_executed"), proposal_id), signer);
+
+        Ok(())
+    }
+
+    pub fn get_admin_proposal(env: Env, proposal_id: u64) -> Result<AdminProposal, ContractError> {
+        insurance_contracts::authorization::get_admin_proposal(&env, proposal_id)
+            .ok_or(ContractError::NotFound)
+    }
+
+    pub fn get_multisig_config(env: Env) -> Option<MultisigConfig> {
+        insurance_contracts::authorization::get_multisig_config(&env)
+    }
+
+    /// Links the governance contract allowed to upgrade this contract (admin only, once).
+    /// The link cannot be repointed afterwards, so upgrades always go through that contract's votes.
+    pub fn set_governance_contract(
+        env: Env,
+        admin: Address,
+        governance: Address,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        if env.storage().persistent().has(&GOVERNANCE_CONTRACT) {
+            return Err(ContractError::AlreadyInitialized);
+        }
+        require_admin_action(
+            &env,
+            &admin,
+            &Symbol::new(&env, "set_governance_contract"),
+            (governance.clone(),).into_val(&env),
+        )?;
+        validate_address(&env, &governance)?;
+
+        env.storage().persistent().set(&GOVERNANCE_CONTRACT, &governance);
+
+        env.events().publish((Symbol::new(&env, "governance_set"), ()), governance);
+
+        Ok(())
+    }
+
+    /// Governance contract allowed to upgrade and emergency-pause this contract, if set.
+    pub fn get_governance_contract(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&GOVERNANCE_CONTRACT)
+    }
+
+    /// Whether `contract_address` is trusted for cross-contract calls.
+    pub fn is_trusted_contract(env: Env, contract_address: Address) -> bool {
+        insurance_contracts::authorization::is_trusted_contract(&env, &contract_address)
+    }
+
+    /// Entry points a trusted contract is limited to (`None` if unscoped or untrusted)
+    pub fn get_trusted_functions(env: Env, contract_address: Address) -> Option<Vec<Symbol>> {
+        insurance_contracts::authorization::get_trusted_functions(&env, &contract_address)
+    }
+
+    pub fn get_admin(env: Env) -> Result<Address, ContractError> {
+        insurance_contracts::authorization::get_admin(&env).ok_or(ContractError::NotInitialized)
+    }
+
+    /// Configured (policy contract, risk pool) pair.
+    pub fn get_config(env: Env) -> Result<(Address, Address), ContractError> {
+        env.storage().persistent().get(&CONFIG).ok_or(ContractError::NotInitialized)
+    }
+
+    /// Blocks claim settlements until `until` as an emergency council action.
+    /// Only callable by the governance contract; passing 0 lifts the freeze.
+    pub fn freeze_settlements(env: Env, until: u64) -> Result<(), ContractError> {
+        let governance: Address = env
+            .storage()
+            .persistent()
+            .get(&GOVERNANCE_CONTRACT)
+            .ok_or(ContractError::NotInitialized)?;
+        governance.require_auth();
+
+        env.storage().persistent().set(&SETTLEMENT_FREEZE, &until);
+
+        env.events().publish((Symbol::new(&env, "settlements_frozen"), ()), until);
+
+        Ok(())
+    }
+
+    /// Timestamp until which claim settlements are frozen (0 or past means not frozen)
+    pub fn get_settlement_freeze(env: Env) -> u64 {
+        settlement_freeze_until(&env)
+    }
+
+    /// Pauses or unpauses the contract as an emergency council action.
+    /// Only callable by the governance contract; the pause is lifted again unless ratified.
+    pub fn set_emergency_pause(env: Env, paused: bool) -> Result<(), ContractError> {
+        let governance: Address = env
+            .storage()
+            .persistent()
+            .get(&GOVERNANCE_CONTRACT)
+            .ok_or(ContractError::NotInitialized)?;
+        governance.require_auth();
+
+        set_paused(&env, paused);
+
+        env.events().publish((Symbol::new(&env, "emergency_pause"), ()), paused);
+
+        Ok(())
+    }
+
+    /// Installs new wasm and migrates to `new_version`.
+    /// Only callable by the governance contract when executing a passed `ContractUpgrade` proposal.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>, new_version: u32) -> Result<(), ContractError> {
+        let governance: Address = env
+            .storage()
+            .persistent()
+            .get(&GOVERNANCE_CONTRACT)
+            .ok_or(ContractError::NotInitialized)?;
+        governance.require_auth();
+
+        UpgradeableContract::upgrade_wasm(
+            &env,
+            governance,
+            new_wasm_hash.clone(),
+            new_version,
+            default_migration_hook,
+        )
+        .map_err(map_versioning_error)?;
+
+        env.events().publish((Symbol::new(&env, "upgraded"), new_version), new_wasm_hash);
+
+        Ok(())
+    }
+
+    pub fn get_version(env: Env) -> Result<u32, ContractError> {
+        VersionManager::current_version(&env).map_err(map_versioning_error)
+    }
+
+    pub fn get_version_history(env: Env) -> Result<Vec<VersionTransition>, ContractError> {
+        VersionManager::version_history(&env).map_err(map_versioning_error)
+    }
+
     pub fn pause(env: Env, admin: Address) -> Result<(), ContractError> {
         // Verify identity and require admin permission
         admin.require_auth();
-        require_admin(&env, &admin)?;
+        require_admin_action(&env, &admin, &Symbol::new(&env, "pause"), Vec::new(&env))?;
 
         set_paused(&env, true);
 
@@ -641,7 +971,7 @@ impl ClaimsContract {
     pub fn unpause(env: Env, admin: Address) -> Result<(), ContractError> {
         // Verify identity and require admin permission
         admin.require_auth();
-        require_admin(&env, &admin)?;
+        require_admin_action(&env, &admin, &Symbol::new(&env, "unpause"), Vec::new(&env))?;
 
         set_paused(&env, false);
 
@@ -650,20 +980,21 @@ impl ClaimsContract {
         Ok(())
     }
 
-    /// Grant claim processor role to an address (admin only)
+    /// Grant claim processor role to an address, optionally until `expires_at` (admin only)
     pub fn grant_processor_role(
         env: Env,
         admin: Address,
         processor: Address,
+        expires_at: Option<u64>,
     ) -> Result<(), ContractError> {
         admin.require_auth();
-        require_admin(&env, &admin)?;
 
         insurance_contracts::authorization::grant_role(
             &env,
             &admin,
             &processor,
             Role::ClaimProcessor,
+            expires_at,
         )?;
 
         env.events().publish((symbol_short!("role_gr"), processor.clone()), admin);
@@ -678,9 +1009,8 @@ impl ClaimsContract {
         processor: Address,
     ) -> Result<(), ContractError> {
         admin.require_auth();
-        require_admin(&env, &admin)?;
 
-        insurance_contracts::authorization::revoke_role(&env, &admin, &processor)?;
+        insurance_contracts::authorization::revoke_role(&env, &admin, &processor, Role::ClaimProcessor)?;
 
         env.events().publish((symbol_short!("role_rv"), processor.clone()), admin);
 
@@ -692,6 +1022,26 @@ impl ClaimsContract {
         get_role(&env, &address)
     }
 
+    /// Get every role held by an address
+    pub fn get_user_roles(env: Env, address: Address) -> Vec<Role> {
+        insurance_contracts::authorization::get_roles(&env, &address)
+    }
+
+    /// Get the active role grants of an address with their expiry
+    pub fn get_role_grants(env: Env, address: Address) -> Vec<RoleGrant> {
+        insurance_contracts::authorization::get_role_grants(&env, &address)
+    }
+
+    /// Get the current holders of a role, paginated by list position (page size capped at 50)
+    pub fn get_role_members(env: Env, role: Role, start_index: u32, limit: u32) -> Vec<Address> {
+        insurance_contracts::authorization::get_role_members(&env, role, start_index, limit)
+    }
+
+    /// Get role grant/revoke/transfer history, oldest first, starting at entry `cursor`
+    pub fn get_role_audit_log(env: Env, cursor: u64, limit: u32) -> Vec<RoleAuditEntry> {
+        insurance_contracts::authorization::get_role_audit_log(&env, cursor, limit)
+    }
+
     /// Returns the total number of claims submitted.
     pub fn get_claim_count(env: Env) -> u64 {
         env.storage()
@@ -1090,7 +1440,7 @@ mod tests {
         initialize_contract(&env, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone(), None).unwrap();
 
         let claim_id = ClaimsContract::submit_claim(
             env.clone(),
@@ -1125,13 +1475,44 @@ mod tests {
         assert_eq!(result, Err(ContractError::Unauthorized));
     }
 
+    #[test]
+    fn test_start_review_by_delegated_session_key() {
+        let (env, admin, policy_contract, risk_pool, user) = setup_test_env();
+        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+
+        let processor = Address::generate(&env);
+        let session_key = Address::generate(&env);
+        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone(), None).unwrap();
+
+        let mut functions = Vec::new(&env);
+        functions.push_back(Symbol::new(&env, "start_review"));
+        ClaimsContract::delegate_operator(
+            env.clone(),
+            processor.clone(),
+            session_key.clone(),
+            functions,
+            0,
+            env.ledger().timestamp() + 3_600,
+        )
+        .unwrap();
+
+        let claim_id = ClaimsContract::submit_claim(env.clone(), user.clone(), 1, 1000).unwrap();
+
+        let result = ClaimsContract::reject_claim(env.clone(), session_key.clone(), claim_id);
+        assert_eq!(result, Err(ContractError::Unauthorized));
+
+        ClaimsContract::start_review(env.clone(), session_key.clone(), claim_id).unwrap();
+        let claim = ClaimsContract::get_claim(env.clone(), claim_id).unwrap();
+        assert_eq!(claim.3, ClaimStatus::UnderReview);
+    }
+
     #[test]
     fn test_start_review_invalid_state_transition() {
         let (env, admin, policy_contract, risk_pool, user) = setup_test_env();
         initialize_contract(&env, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone(), None).unwrap();
 
         let claim_id = ClaimsContract::submit_claim(
             env.clone(),
@@ -1154,7 +1535,7 @@ mod tests {
         initialize_contract(&env, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone(), None).unwrap();
 
         let result = ClaimsContract::start_review(env.clone(), processor.clone(), 99999);
         assert_eq!(result, Err(ContractError::NotFound));
@@ -1170,7 +1551,7 @@ mod tests {
         initialize_contract(&env, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone(), None).unwrap();
 
         let claim_id = ClaimsContract::submit_claim(
             env.clone(),
@@ -1198,7 +1579,7 @@ mod tests {
         initialize_contract(&env, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone(), None).unwrap();
 
         let claim_id = ClaimsContract::submit_claim(
             env.clone(),
@@ -1218,7 +1599,7 @@ mod tests {
         initialize_contract(&env, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone(), None).unwrap();
 
         let unauthorized_user = Address::generate(&env);
 
@@ -1245,7 +1626,7 @@ mod tests {
         initialize_contract(&env, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone(), None).unwrap();
 
         let claim_id = ClaimsContract::submit_claim(
             env.clone(),
@@ -1269,7 +1650,7 @@ mod tests {
         initialize_contract(&env, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone(), None).unwrap();
 
         let claim_id = ClaimsContract::submit_claim(
             env.clone(),
@@ -1289,7 +1670,7 @@ mod tests {
         initialize_contract(&env, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone(), None).unwrap();
 
         let unauthorized_user = Address::generate(&env);
 
@@ -1316,7 +1697,7 @@ mod tests {
         initialize_contract(&env, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone(), None).unwrap();
 
         let claim_id = ClaimsContract::submit_claim(
             env.clone(),
@@ -1336,7 +1717,7 @@ mod tests {
         initialize_contract(&env, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone(), None).unwrap();
 
         let claim_id = ClaimsContract::submit_claim(
             env.clone(),
@@ -1358,7 +1739,7 @@ mod tests {
         initialize_contract(&env, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone(), None).unwrap();
 
         let unauthorized_user = Address::generate(&env);
 
@@ -1497,6 +1878,7 @@ mod tests {
             env.clone(),
             admin.clone(),
             processor.clone(),
+            None,
         );
 
         assert!(result.is_ok());
@@ -1517,6 +1899,7 @@ mod tests {
             env.clone(),
             unauthorized_user.clone(),
             processor.clone(),
+            None,
         );
 
         assert_eq!(result, Err(ContractError::Unauthorized));
@@ -1533,6 +1916,7 @@ mod tests {
             env.clone(),
             admin.clone(),
             processor.clone(),
+            None,
         ).unwrap();
 
         let result = ClaimsContract::revoke_processor_role(
@@ -1559,6 +1943,7 @@ mod tests {
             env.clone(),
             admin.clone(),
             processor.clone(),
+            None,
         ).unwrap();
 
         let result = ClaimsContract::revoke_processor_role(
@@ -1580,7 +1965,7 @@ mod tests {
         initialize_contract(&env, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone(), None).unwrap();
 
         // Submit claim
         let claim_id = ClaimsContract::submit_claim(
@@ -0,0 +1,479 @@
+//! I5: Authorization Consistency checker
+//!
+//! Every contract keeps its own admin, governance link and trusted-contract table.
+//! This checker reads them from a full deployment through cross-contract view calls
+//! and reports every place where they disagree with the intended wiring.
+//!
+//! It only reads state, so it can run from a view entry point, an off-chain
+//! simulation or a test harness.
+
+use authorization::Role;
+use soroban_sdk::{contracttype, Address, Env, IntoVal, Symbol, TryFromVal, Val, Vec};
+
+use crate::InvariantError;
+
+/// Addresses of a full protocol deployment and the admin they should share
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolDeployment {
+    pub admin: Address,
+    pub policy: Address,
+    pub claims: Address,
+    pub risk_pool: Address,
+    pub treasury: Address,
+    pub governance: Address,
+    pub slashing: Address,
+    pub oracle: Address,
+}
+
+/// Single I5 violation found by the checker
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AuthorizationMismatch {
+    /// (contract, view) could not be read: missing entry point or failing call
+    Unreadable(Address, Symbol),
+    /// (contract, actual admin) differs from the deployment admin
+    AdminMismatch(Address, Address),
+    /// (contract, holder) holds the `Admin` role without being the contract admin
+    StrayAdmin(Address, Address),
+    /// (contract, actual governance link) differs from the deployment governance contract
+    GovernanceMismatch(Address, Option<Address>),
+    /// (contract, config field, actual address) points at the wrong contract
+    LinkMismatch(Address, Symbol, Address),
+    /// (truster, trustee): the truster does not trust a contract it depends on
+    MissingTrust(Address, Address),
+    /// (truster, trustee, actual allowlist): the trust is unscoped or does not match
+    /// the entry points the truster expects the trustee to call
+    TrustScopeMismatch(Address, Address, Option<Vec<Symbol>>),
+}
+
+/// Call a view on `contract`, recording it as unreadable on failure
+fn read_view<T>(
+    env: &Env,
+    contract: &Address,
+    function: &str,
+    args: Vec<Val>,
+    mismatches: &mut Vec<AuthorizationMismatch>,
+) -> Option<T>
+where
+    T: TryFromVal<Env, Val>,
+{
+    let function = Symbol::new(env, function);
+    match env.try_invoke_contract::<T, soroban_sdk::Error>(contract, &function, args) {
+        Ok(Ok(value)) => Some(value),
+        _ => {
+            mismatches.push_back(AuthorizationMismatch::Unreadable(contract.clone(), function));
+            None
+        }
+    }
+}
+
+/// Check the contract admin and return it, if readable
+fn check_admin(
+    env: &Env,
+    contract: &Address,
+    expected: &Address,
+    mismatches: &mut Vec<AuthorizationMismatch>,
+) -> Option<Address> {
+    let admin: Option<Address> = read_view(env, contract, "get_admin", Vec::new(env), mismatches);
+    if let Some(admin) = &admin {
+        if admin != expected {
+            mismatches.push_back(AuthorizationMismatch::AdminMismatch(contract.clone(), admin.clone()));
+        }
+    }
+    admin
+}
+
+/// Contracts using the shared authorization crate must not have `Admin` holders besides their admin
+fn check_admin_role_holders(
+    env: &Env,
+    contract: &Address,
+    admin: Option<Address>,
+    mismatches: &mut Vec<AuthorizationMismatch>,
+) {
+    let holders: Option<Vec<Address>> = read_view(
+        env,
+        contract,
+        "get_role_members",
+        (Role::Admin, 0u32, 0u32).into_val(env),
+        mismatches,
+    );
+    if let (Some(admin), Some(holders)) = (admin, holders) {
+        for holder in holders.iter() {
+            if holder != admin {
+                mismatches.push_back(AuthorizationMismatch::StrayAdmin(contract.clone(), holder));
+            }
+        }
+    }
+}
+
+fn check_governance_link(
+    env: &Env,
+    contract: &Address,
+    governance: &Address,
+    mismatches: &mut Vec<AuthorizationMismatch>,
+) {
+    let linked: Option<Option<Address>> =
+        read_view(env, contract, "get_governance_contract", Vec::new(env), mismatches);
+    if let Some(linked) = linked {
+        if linked.as_ref() != Some(governance) {
+            mismatches.push_back(AuthorizationMismatch::GovernanceMismatch(contract.clone(), linked));
+        }
+    }
+}
+
+fn check_link(
+    contract: &Address,
+    field: Symbol,
+    actual: Address,
+    expected: &Address,
+    mismatches: &mut Vec<AuthorizationMismatch>,
+) {
+    if actual != *expected {
+        mismatches.push_back(AuthorizationMismatch::LinkMismatch(contract.clone(), field, actual));
+    }
+}
+
+fn check_trust(
+    env: &Env,
+    truster: &Address,
+    trustee: &Address,
+    mismatches: &mut Vec<AuthorizationMismatch>,
+) {
+    let trusted: Option<bool> = read_view(
+        env,
+        truster,
+        "is_trusted_contract",
+        (trustee.clone(),).into_val(env),
+        mismatches,
+    );
+    if trusted == Some(false) {
+        mismatches.push_back(AuthorizationMismatch::MissingTrust(truster.clone(), trustee.clone()));
+    }
+}
+
+/// Trust must be scoped to exactly `expected` entry points
+fn check_trust_scope(
+    env: &Env,
+    truster: &Address,
+    trustee: &Address,
+    expected: &[&str],
+    mismatches: &mut Vec<AuthorizationMismatch>,
+) {
+    let scope: Option<Option<Vec<Symbol>>> = read_view(
+        env,
+        truster,
+        "get_trusted_functions",
+        (trustee.clone(),).into_val(env),
+        mismatches,
+    );
+    let Some(scope) = scope else {
+        return;
+    };
+    let matches = match &scope {
+        Some(functions) => {
+            functions.len() as usize == expected.len()
+                && expected.iter().all(|function| functions.contains(Symbol::new(env, function)))
+        }
+        None => false,
+    };
+    if !matches {
+        mismatches.push_back(AuthorizationMismatch::TrustScopeMismatch(
+            truster.clone(),
+            trustee.clone(),
+            scope,
+        ));
+    }
+}
+
+/// Collect every I5 violation in a deployment (empty when consistent)
+pub fn check_authorization_consistency(
+    env: &Env,
+    deployment: &ProtocolDeployment,
+) -> Vec<AuthorizationMismatch> {
+    let mut mismatches = Vec::new(env);
+    let d = deployment;
+
+    // One protocol admin everywhere; contracts on the shared authorization crate
+    // additionally list their `Admin` role holders
+    for contract in [&d.policy, &d.claims, &d.risk_pool, &d.treasury, &d.governance] {
+        let admin = check_admin(env, contract, &d.admin, &mut mismatches);
+        check_admin_role_holders(env, contract, admin, &mut mismatches);
+    }
+    for contract in [&d.slashing, &d.oracle] {
+        check_admin(env, contract, &d.admin, &mut mismatches);
+    }
+
+    // Upgrades and emergency actions come from the deployment's governance contract
+    for contract in [&d.policy, &d.claims, &d.risk_pool, &d.treasury, &d.slashing, &d.oracle] {
+        check_governance_link(env, contract, &d.governance, &mut mismatches);
+    }
+
+    // Configured counterparties
+    let policy_risk_pool: Option<Address> =
+        read_view(env, &d.policy, "get_risk_pool", Vec::new(env), &mut mismatches);
+    if let Some(risk_pool) = policy_risk_pool {
+        check_link(&d.policy, Symbol::new(env, "risk_pool"), risk_pool, &d.risk_pool, &mut mismatches);
+    }
+    let claims_config: Option<(Address, Address)> =
+        read_view(env, &d.claims, "get_config", Vec::new(env), &mut mismatches);
+    if let Some((policy, risk_pool)) = claims_config {
+        check_link(&d.claims, Symbol::new(env, "policy"), policy, &d.policy, &mut mismatches);
+        check_link(&d.claims, Symbol::new(env, "risk_pool"), risk_pool, &d.risk_pool, &mut mismatches);
+    }
+    let governance_slashing: Option<Address> =
+        read_view(env, &d.governance, "get_slashing_contract", Vec::new(env), &mut mismatches);
+    if let Some(slashing) = governance_slashing {
+        check_link(&d.governance, Symbol::new(env, "slashing"), slashing, &d.slashing, &mut mismatches);
+    }
+
+    // Trusted-contract tables must cover every cross-contract dependency
+    check_trust(env, &d.risk_pool, &d.claims, &mut mismatches);
+    check_trust(env, &d.claims, &d.policy, &mut mismatches);
+    check_trust(env, &d.claims, &d.risk_pool, &mut mismatches);
+    check_trust(env, &d.claims, &d.oracle, &mut mismatches);
+    check_trust(env, &d.policy, &d.risk_pool, &mut mismatches);
+    for depositor in [&d.policy, &d.claims, &d.slashing] {
+        check_trust(env, &d.treasury, depositor, &mut mismatches);
+    }
+
+    // Scoped trust must match the entry points each counterparty is called through
+    let payouts = ["reserve_liquidity", "payout_reserved_claim"];
+    check_trust_scope(env, &d.risk_pool, &d.claims, &payouts, &mut mismatches);
+    check_trust_scope(env, &d.claims, &d.risk_pool, &payouts, &mut mismatches);
+    check_trust_scope(env, &d.claims, &d.policy, &["get_policy"], &mut mismatches);
+    check_trust_scope(
+        env,
+        &d.claims,
+        &d.oracle,
+        &["get_submission_count", "resolve_oracle_data"],
+        &mut mismatches,
+    );
+
+    mismatches
+}
+
+/// Fail with `AuthorizationViolation` unless the deployment is consistent
+pub fn require_authorization_consistency(
+    env: &Env,
+    deployment: &ProtocolDeployment,
+) -> Result<(), InvariantError> {
+    if check_authorization_consistency(env, deployment).is_empty() {
+        Ok(())
+    } else {
+        Err(InvariantError::AuthorizationViolation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{contract, contractimpl, symbol_short, Map};
+
+    /// Stand-in exposing the views every protocol contract provides
+    fn link(env: &Env, index: u32) -> Address {
+        let links: Vec<Address> = env.storage().instance().get(&symbol_short!("links")).unwrap();
+        links.get(index).unwrap()
+    }
+
+    #[contract]
+    struct MockContract;
+
+    #[contractimpl]
+    impl MockContract {
+        pub fn setup(env: Env, admin: Address, governance: Address, trusted: Vec<Address>, links: Vec<Address>) {
+            env.storage().instance().set(&symbol_short!("admin"), &admin);
+            env.storage().instance().set(&symbol_short!("gov"), &governance);
+            env.storage().instance().set(&symbol_short!("trusted"), &trusted);
+            env.storage().instance().set(&symbol_short!("links"), &links);
+        }
+
+        pub fn set_trusted_functions(env: Env, contract_address: Address, functions: Vec<Symbol>) {
+            let mut scopes: Map<Address, Vec<Symbol>> =
+                env.storage().instance().get(&symbol_short!("scopes")).unwrap_or(Map::new(&env));
+            scopes.set(contract_address, functions);
+            env.storage().instance().set(&symbol_short!("scopes"), &scopes);
+        }
+
+        pub fn get_admin(env: Env) -> Address {
+            env.storage().instance().get(&symbol_short!("admin")).unwrap()
+        }
+
+        pub fn get_governance_contract(env: Env) -> Option<Address> {
+            env.storage().instance().get(&symbol_short!("gov"))
+        }
+
+        pub fn get_role_members(env: Env, _role: Role, _start_index: u32, _limit: u32) -> Vec<Address> {
+            let mut members = Vec::new(&env);
+            members.push_back(Self::get_admin(env));
+            members
+        }
+
+        pub fn is_trusted_contract(env: Env, contract_address: Address) -> bool {
+            let trusted: Vec<Address> = env.storage().instance().get(&symbol_short!("trusted")).unwrap();
+            trusted.contains(&contract_address)
+        }
+
+        pub fn get_trusted_functions(env: Env, contract_address: Address) -> Option<Vec<Symbol>> {
+            let scopes: Map<Address, Vec<Symbol>> =
+                env.storage().instance().get(&symbol_short!("scopes")).unwrap_or(Map::new(&env));
+            scopes.get(contract_address)
+        }
+
+        pub fn get_risk_pool(env: Env) -> Address {
+            link(&env, 0)
+        }
+
+        pub fn get_config(env: Env) -> (Address, Address) {
+            (link(&env, 0), link(&env, 1))
+        }
+
+        pub fn get_slashing_contract(env: Env) -> Address {
+            link(&env, 0)
+        }
+    }
+
+    fn list(env: &Env, items: &[&Address]) -> Vec<Address> {
+        let mut v = Vec::new(env);
+        for item in items {
+            v.push_back((*item).clone());
+        }
+        v
+    }
+
+    fn setup(env: &Env, d: &ProtocolDeployment, contract: &Address, trusted: Vec<Address>, links: Vec<Address>) {
+        MockContractClient::new(env, contract).setup(&d.admin, &d.governance, &trusted, &links);
+    }
+
+    fn scope(env: &Env, truster: &Address, trustee: &Address, functions: &[&str]) {
+        let mut scoped = Vec::new(env);
+        for function in functions {
+            scoped.push_back(Symbol::new(env, function));
+        }
+        MockContractClient::new(env, truster).set_trusted_functions(trustee, &scoped);
+    }
+
+    /// Wire every mock except the treasury and risk pool
+    fn setup_counterparties(env: &Env, d: &ProtocolDeployment) {
+        setup(env, d, &d.policy, list(env, &[&d.risk_pool]), list(env, &[&d.risk_pool]));
+        setup(
+            env,
+            d,
+            &d.claims,
+            list(env, &[&d.policy, &d.risk_pool, &d.oracle]),
+            list(env, &[&d.policy, &d.risk_pool]),
+        );
+        scope(env, &d.claims, &d.policy, &["get_policy"]);
+        scope(env, &d.claims, &d.risk_pool, &["reserve_liquidity", "payout_reserved_claim"]);
+        scope(env, &d.claims, &d.oracle, &["get_submission_count", "resolve_oracle_data"]);
+        setup(env, d, &d.governance, list(env, &[]), list(env, &[&d.slashing]));
+        setup(env, d, &d.slashing, list(env, &[]), list(env, &[]));
+        setup(env, d, &d.oracle, list(env, &[]), list(env, &[]));
+    }
+
+    fn deploy(env: &Env) -> ProtocolDeployment {
+        let deployment = ProtocolDeployment {
+            admin: Address::generate(env),
+            policy: env.register(MockContract, ()),
+            claims: env.register(MockContract, ()),
+            risk_pool: env.register(MockContract, ()),
+            treasury: env.register(MockContract, ()),
+            governance: env.register(MockContract, ()),
+            slashing: env.register(MockContract, ()),
+            oracle: env.register(MockContract, ()),
+        };
+        let d = &deployment;
+        setup_counterparties(env, d);
+        setup(env, d, &d.risk_pool, list(env, &[&d.claims]), list(env, &[]));
+        scope(env, &d.risk_pool, &d.claims, &["reserve_liquidity", "payout_reserved_claim"]);
+        setup(env, d, &d.treasury, list(env, &[&d.policy, &d.claims, &d.slashing]), list(env, &[]));
+        deployment
+    }
+    #[test]
+    fn test_consistent_deployment_passes() {
+        let env = Env::default();
+        let deployment = deploy(&env);
+
+        assert!(check_authorization_consistency(&env, &deployment).is_empty());
+        assert_eq!(require_authorization_consistency(&env, &deployment), Ok(()));
+    }
+
+    #[test]
+    fn test_reports_missing_trust_and_wrong_links() {
+        let env = Env::default();
+        let deployment = deploy(&env);
+        let d = &deployment;
+        let rogue = Address::generate(&env);
+
+        // Risk pool no longer trusts claims, the oracle answers to another admin and
+        // claims lets the risk pool call one entry point too many
+        MockContractClient::new(&env, &d.risk_pool).setup(&d.admin, &d.governance, &Vec::new(&env), &Vec::new(&env));
+        MockContractClient::new(&env, &d.oracle).setup(&rogue, &d.governance, &Vec::new(&env), &Vec::new(&env));
+        scope(&env, &d.claims, &d.risk_pool, &["reserve_liquidity", "payout_reserved_claim", "set_fee"]);
+
+        let mismatches = check_authorization_consistency(&env, d);
+        assert!(mismatches.contains(AuthorizationMismatch::MissingTrust(d.risk_pool.clone(), d.claims.clone())));
+        assert!(mismatches.contains(AuthorizationMismatch::AdminMismatch(d.oracle.clone(), rogue)));
+        assert!(mismatches.iter().any(|mismatch| matches!(
+            mismatch,
+            AuthorizationMismatch::TrustScopeMismatch(truster, trustee, _)
+                if truster == d.claims && trustee == d.risk_pool
+        )));
+        assert_eq!(mismatches.len(), 3);
+        assert_eq!(
+            require_authorization_consistency(&env, d),
+            Err(InvariantError::AuthorizationViolation)
+        );
+    }
+
+    #[test]
+    fn test_reports_unreadable_contract() {
+        let env = Env::default();
+        let mut deployment = deploy(&env);
+        deployment.slashing = env.register(authorization_less::Empty, ());
+
+        let mismatches = check_authorization_consistency(&env, &deployment);
+        assert!(mismatches.contains(AuthorizationMismatch::Unreadable(
+            deployment.slashing.clone(),
+            Symbol::new(&env, "get_admin")
+        )));
+    }
+
+    #[test]
+    fn test_reports_self_administered_treasury() {
+        let env = Env::default();
+        let deployment = deploy(&env);
+        let d = &deployment;
+
+        // A treasury that names itself as admin and does not take slashing fees
+        MockContractClient::new(&env, &d.treasury).setup(
+            &d.treasury,
+            &d.governance,
+            &list(&env, &[&d.policy, &d.claims]),
+            &Vec::new(&env),
+        );
+
+        let mismatches = check_authorization_consistency(&env, d);
+        assert!(mismatches.contains(AuthorizationMismatch::AdminMismatch(
+            d.treasury.clone(),
+            d.treasury.clone()
+        )));
+        assert!(mismatches.contains(AuthorizationMismatch::MissingTrust(d.treasury.clone(), d.slashing.clone())));
+        assert_eq!(mismatches.len(), 2);
+    }
+
+    mod authorization_less {
+        use soroban_sdk::{contract, contractimpl};
+
+        #[contract]
+        pub struct Empty;
+
+        #[contractimpl]
+        impl Empty {
+            pub fn ping() -> u32 {
+                1
+            }
+        }
+    }
+}
@@ -9,6 +9,7 @@
 //! - I3: Claim State Validity: Claims must be in valid state transitions
 //! - I4: Amount Non-Negativity: All financial amounts must be non-negative
 //! - I5: Authorization Consistency: Role assignments must be consistent across contracts
+//!   (checked across a deployment by [`authorization_consistency`])
 //! - I6: Coverage Constraint: `claim_amount <= policy_coverage_amount`
 //! - I7: Premium Validity: `premium_amount > 0` for active policies
 
@@ -16,6 +17,8 @@
 
 use soroban_sdk::contracterror;
 
+pub mod authorization_consistency;
+
 /// Invariant violation error codes
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -96,6 +99,7 @@ impl ProtocolInvariants {
     /// - PolicyManager: policy issuance
     ///
     /// Prevents privilege escalation through role inconsistency.
+    /// Checked with `authorization_consistency::check_authorization_consistency`.
     pub const AUTHORIZATION_CONSISTENCY: &'static str = "I5:AuthConsistent";
 
     /// I6: Coverage Constraint Invariant
@@ -11,16 +11,46 @@ use soroban_sdk::{contracttype, Address, Env, Symbol, String, BytesN};
 pub mod authorization {
     pub use authorization::{
         Role, RoleKey, AuthError,
-        initialize_admin, get_admin, grant_role, revoke_role, get_role,
+        initialize_admin, get_admin, grant_role, revoke_role, get_role, get_roles,
+        RoleGrant, get_role_grants, migrate_role_entry,
+        RoleAuditAction, RoleAuditEntry, get_role_members, get_role_audit_log,
         has_role, require_role, require_admin, has_any_role, require_any_role,
         require_policy_management, require_claim_processing,
         require_risk_pool_management, require_governance_permission,
-        register_trusted_contract, unregister_trusted_contract,
+        register_unscoped_trust, unregister_trusted_contract,
         is_trusted_contract, require_trusted_contract,
+        register_trusted_contract_for, get_trusted_functions, require_trusted_contract_for,
         verify_and_require_role, verify_and_check_permission,
+        Delegation, delegate_operator, revoke_delegation, get_delegation,
+        MultisigConfig, AdminAction, AdminProposal,
+        require_admin_action, admin_action_hash, is_admin_initialized,
+        get_multisig_config, is_multisig_enabled,
+        enable_multisig_admin, propose_admin_action, approve_admin_action,
+        execute_admin_action, get_admin_proposal,
+        PendingAdminTransfer, propose_admin_transfer, cancel_admin_transfer,
+        accept_admin, get_pending_admin, renounce_role,
     };
 }
 
+/// Re-export protocol-wide constants
+/// Use limits like: use insurance_contracts::constants::MIN_ALLOCATION_AMOUNT;
+pub mod constants {
+    pub use shared::constants::*;
+}
+
+/// Re-export the protocol parameter registry
+/// Read live values like: use insurance_contracts::parameters::read_parameter;
+pub mod parameters {
+    pub use shared::parameters::*;
+}
+
+/// Re-export contract versioning and wasm upgrade helpers
+/// Upgrade contracts like: use insurance_contracts::upgradeable::UpgradeableContract;
+pub mod upgradeable {
+    pub use shared::upgradeable::{default_migration_hook, UpgradeableContract};
+    pub use shared::versioning::{VersionManager, VersionTransition, VersioningError};
+}
+
 /// Common contract types shared across all insurance contracts
 pub mod types {
     use super::*;
@@ -111,9 +141,6 @@ pub mod errors {
         /// Contract not trusted for cross-contract calls
         NotTrustedContract = 13,
 
-        /// 🔐 Evidence-specific errors
-        EvidenceAlreadyExists = 20,
-        EvidenceNotFound = 21,
         /// Evidence already exists for this claim
         EvidenceAlreadyExists = 20,
         /// Evidence not found
@@ -130,6 +157,10 @@ pub mod errors {
                 super::authorization::AuthError::InvalidRole => ContractError::InvalidRole,
                 super::authorization::AuthError::RoleNotFound => ContractError::RoleNotFound,
                 super::authorization::AuthError::NotTrustedContract => ContractError::NotTrustedContract,
+                super::authorization::AuthError::InvalidInput => ContractError::InvalidInput,
+                super::authorization::AuthError::NotFound => ContractError::NotFound,
+                super::authorization::AuthError::AlreadyExists => ContractError::AlreadyExists,
+                super::authorization::AuthError::InvalidState => ContractError::InvalidState,
             }
         }
     }
@@ -1,7 +1,14 @@
 #![no_std]
 
+use shared::parameters::{
+    read_parameter, ORACLE_MAJORITY_PERCENT, ORACLE_MIN_SUBMISSIONS,
+    ORACLE_OUTLIER_DEVIATION_PERCENT, ORACLE_STALENESS_SECONDS,
+};
+use shared::upgradeable::{default_migration_hook, UpgradeableContract};
+use shared::versioning::{VersionManager, VersionTransition, VersioningError};
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, BytesN, Env,
+    Symbol, Vec,
 };
 
 // ============================================================================
@@ -15,6 +22,8 @@ const ORACLE_DATA: Symbol = symbol_short!("ORA_DATA");
 const ORACLE_HISTORY: Symbol = symbol_short!("ORA_HIST");
 const SUBMISSIONS: Symbol = symbol_short!("SUBS");
 const THRESHOLDS: Symbol = symbol_short!("THRESH");
+const PARAM_REGISTRY: Symbol = symbol_short!("PARAM_REG");
+const GOVERNANCE_CONTRACT: Symbol = symbol_short!("GOV_CON");
 
 // Default thresholds for oracle validation
 const DEFAULT_MIN_SUBMISSIONS: u32 = 3;
@@ -41,6 +50,7 @@ pub enum OracleError {
     ConsensusNotReached = 10,
     InvalidThreshold = 11,
     DuplicateSubmission = 12,
+    MigrationFailed = 13,
 }
 
 // ============================================================================
@@ -146,15 +156,56 @@ fn require_admin(env: &Env) -> Result<Address, OracleError> {
     Ok(admin)
 }
 
+fn map_versioning_error(e: VersioningError) -> OracleError {
+    match e {
+        VersioningError::NotInitialized => OracleError::NotInitialized,
+        VersioningError::MigrationInProgress => OracleError::Paused,
+        VersioningError::UnauthorizedUpgrade => OracleError::Unauthorized,
+        VersioningError::InvalidVersionNumber => OracleError::InvalidInput,
+        VersioningError::VersionMismatch
+        | VersioningError::MigrationFailed
+        | VersioningError::MigrationHookFailed
+        | VersioningError::SchemaValidationFailed
+        | VersioningError::RollbackFailed => OracleError::MigrationFailed,
+    }
+}
+
 fn is_paused(env: &Env) -> bool {
     env.storage().persistent().get(&PAUSED).unwrap_or(false)
 }
 
-fn get_thresholds(env: &Env) -> ValidationThreshold {
+/// Reads a live value from the governance parameter registry, if one is configured
+fn registry_parameter(env: &Env, key: &Symbol) -> Option<i128> {
     env.storage()
+        .persistent()
+        .get::<_, Address>(&PARAM_REGISTRY)
+        .and_then(|registry| read_parameter(env, &registry, key))
+}
+
+/// Locally configured thresholds, overridden by any registered governance parameters
+fn get_thresholds(env: &Env) -> ValidationThreshold {
+    let mut thresholds: ValidationThreshold = env
+        .storage()
         .persistent()
         .get(&THRESHOLDS)
-        .unwrap_or_else(ValidationThreshold::default)
+        .unwrap_or_else(ValidationThreshold::default);
+
+    if let Some(value) = registry_parameter(env, &ORACLE_MIN_SUBMISSIONS) {
+        thresholds.min_submissions = u32::try_from(value).unwrap_or(thresholds.min_submissions);
+    }
+    if let Some(value) = registry_parameter(env, &ORACLE_MAJORITY_PERCENT) {
+        thresholds.majority_threshold_percent =
+            u32::try_from(value).unwrap_or(thresholds.majority_threshold_percent);
+    }
+    if let Some(value) = registry_parameter(env, &ORACLE_OUTLIER_DEVIATION_PERCENT) {
+        thresholds.outlier_deviation_percent = value;
+    }
+    if let Some(value) = registry_parameter(env, &ORACLE_STALENESS_SECONDS) {
+        thresholds.staleness_threshold_seconds =
+            u64::try_from(value).unwrap_or(thresholds.staleness_threshold_seconds);
+    }
+
+    thresholds
 }
 
 fn set_thresholds(env: &Env, thresholds: &ValidationThreshold) {
@@ -301,6 +352,84 @@ impl OracleContract {
         Ok(())
     }
 
+    /// Point the oracle at the governance parameter registry (admin only)
+    pub fn set_parameter_registry(env: Env, registry: Address) -> Result<(), OracleError> {
+        let _admin = require_admin(&env)?;
+        env.storage().persistent().set(&PARAM_REGISTRY, &registry);
+        Ok(())
+    }
+
+    /// Link the governance contract allowed to upgrade the oracle (admin only, once).
+    /// The link cannot be repointed afterwards, so upgrades always go through that contract's votes.
+    pub fn set_governance_contract(env: Env, governance: Address) -> Result<(), OracleError> {
+        let _admin = require_admin(&env)?;
+        if env.storage().persistent().has(&GOVERNANCE_CONTRACT) {
+            return Err(OracleError::AlreadyInitialized);
+        }
+        env.storage().persistent().set(&GOVERNANCE_CONTRACT, &governance);
+        Ok(())
+    }
+
+    /// Pauses or unpauses the contract as an emergency council action.
+    /// Only callable by the governance contract; the pause is lifted again unless ratified.
+    pub fn set_emergency_pause(env: Env, paused: bool) -> Result<(), OracleError> {
+        let governance: Address = env
+            .storage()
+            .persistent()
+            .get(&GOVERNANCE_CONTRACT)
+            .ok_or(OracleError::NotInitialized)?;
+        governance.require_auth();
+
+        env.storage().persistent().set(&PAUSED, &paused);
+
+        env.events().publish((Symbol::new(&env, "emergency_pause"), ()), paused);
+
+        Ok(())
+    }
+
+    /// Install new wasm and migrate to `new_version`.
+    /// Only callable by the governance contract when executing a passed `ContractUpgrade` proposal.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>, new_version: u32) -> Result<(), OracleError> {
+        let governance: Address = env
+            .storage()
+            .persistent()
+            .get(&GOVERNANCE_CONTRACT)
+            .ok_or(OracleError::NotInitialized)?;
+        governance.require_auth();
+
+        UpgradeableContract::upgrade_wasm(
+            &env,
+            governance,
+            new_wasm_hash.clone(),
+            new_version,
+            default_migration_hook,
+        )
+        .map_err(map_versioning_error)?;
+
+        env.events().publish((Symbol::new(&env, "upgraded"), new_version), new_wasm_hash);
+
+        Ok(())
+    }
+
+    pub fn get_admin(env: Env) -> Result<Address, OracleError> {
+        env.storage().persistent().get(&ADMIN).ok_or(OracleError::NotInitialized)
+    }
+
+    /// Governance contract allowed to upgrade and emergency-pause the oracle, if set.
+    pub fn get_governance_contract(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&GOVERNANCE_CONTRACT)
+    }
+
+    /// Get the current contract version
+    pub fn get_version(env: Env) -> Result<u32, OracleError> {
+        VersionManager::current_version(&env).map_err(map_versioning_error)
+    }
+
+    /// Get the recorded version transitions, newest first
+    pub fn get_version_history(env: Env) -> Result<Vec<VersionTransition>, OracleError> {
+        VersionManager::version_history(&env).map_err(map_versioning_error)
+    }
+
     /// Get current validation thresholds
     pub fn get_thresholds(env: Env) -> Result<ValidationThreshold, OracleError> {
         Ok(get_thresholds(&env))
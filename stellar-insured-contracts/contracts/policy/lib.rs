@@ -1,17 +1,27 @@
 #![no_std]
 use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Address, Env, Symbol, Vec};
-use soroban_sdk::{contract, contractimpl, contracterror, contracttype, Address, Env, Symbol, Vec};
+use soroban_sdk::{contract, contractimpl, contracterror, contracttype, Address, BytesN, Env, IntoVal, Symbol, Vec};
 
 // Import authorization from the common library
 use insurance_contracts::authorization::{
-    get_role, initialize_admin, register_trusted_contract, require_admin,
-    require_policy_management, Role,
+    get_role, initialize_admin, register_unscoped_trust, require_admin_action,
+    require_policy_management, AdminAction, AdminProposal, MultisigConfig,
+    PendingAdminTransfer, Role, RoleAuditEntry, RoleGrant,
+};
+
+use insurance_contracts::parameters::{
+    read_parameter, POLICY_MAX_COVERAGE, POLICY_MAX_PREMIUM, POLICY_MIN_COVERAGE,
+    POLICY_MIN_PREMIUM,
+};
+use insurance_contracts::upgradeable::{
+    default_migration_hook, UpgradeableContract, VersionManager, VersionTransition,
+    VersioningError,
 };
 
 // Import invariant checks and error types
 use insurance_invariants::{InvariantError, ProtocolInvariants};
 
-// Policy validation constants
+// Policy validation constants (defaults when no parameter registry is configured)
 const MIN_COVERAGE_AMOUNT: i128 = 1_000_000; // 1 unit (assuming 6 decimals)
 const MAX_COVERAGE_AMOUNT: i128 = 1_000_000_000_000_000; // 1M units
 const MIN_PREMIUM_AMOUNT: i128 = 100_000; // 0.1 units
@@ -25,6 +35,10 @@ const MAX_PAGINATION_LIMIT: u32 = 50;
 /// Storage key for the list of active policy IDs
 const ACTIVE_POLICY_LIST: Symbol = Symbol::short("ACT_POL");
 
+/// Storage key for the governance parameter registry address
+const PARAM_REGISTRY: Symbol = Symbol::short("PARAM_REG");
+const GOVERNANCE_CONTRACT: Symbol = Symbol::short("GOV_CON");
+
 #[contract]
 pub struct PolicyContract;
 
@@ -372,6 +386,16 @@ impl From<insurance_contracts::authorization::AuthError> for ContractError {
             insurance_contracts::authorization::AuthError::NotTrustedContract => {
                 ContractError::NotTrustedContract
             }
+            insurance_contracts::authorization::AuthError::InvalidInput => {
+                ContractError::InvalidInput
+            }
+            insurance_contracts::authorization::AuthError::NotFound => ContractError::NotFound,
+            insurance_contracts::authorization::AuthError::AlreadyExists => {
+                ContractError::AlreadyExists
+            }
+            insurance_contracts::authorization::AuthError::InvalidState => {
+                ContractError::InvalidState
+            }
         }
     }
 }
@@ -425,17 +449,44 @@ fn is_valid_policy_state_transition(current: PolicyStatus, next: PolicyStatus) -
 // Bring the shared PolicyStatus into scope for the legacy invariant helper above.
 use insurance_contracts::types::PolicyStatus;
 
+fn map_versioning_error(e: VersioningError) -> ContractError {
+    match e {
+        VersioningError::NotInitialized => ContractError::NotInitialized,
+        VersioningError::MigrationInProgress => ContractError::Paused,
+        VersioningError::UnauthorizedUpgrade => ContractError::Unauthorized,
+        VersioningError::InvalidVersionNumber => ContractError::InvalidInput,
+        VersioningError::VersionMismatch
+        | VersioningError::MigrationFailed
+        | VersioningError::MigrationHookFailed
+        | VersioningError::SchemaValidationFailed
+        | VersioningError::RollbackFailed => ContractError::InvalidState,
+    }
+}
+
+/// Reads a live parameter from the registry, falling back to the compiled-in default.
+fn parameter_or(env: &Env, key: &Symbol, default: i128) -> i128 {
+    env.storage()
+        .persistent()
+        .get::<_, Address>(&PARAM_REGISTRY)
+        .and_then(|registry| read_parameter(env, &registry, key))
+        .unwrap_or(default)
+}
+
 /// I4: Validate coverage amount within bounds
-fn validate_coverage_amount(amount: i128) -> Result<(), ContractError> {
-    if amount < MIN_COVERAGE_AMOUNT || amount > MAX_COVERAGE_AMOUNT {
+fn validate_coverage_amount(env: &Env, amount: i128) -> Result<(), ContractError> {
+    let min = parameter_or(env, &POLICY_MIN_COVERAGE, MIN_COVERAGE_AMOUNT);
+    let max = parameter_or(env, &POLICY_MAX_COVERAGE, MAX_COVERAGE_AMOUNT);
+    if amount < min || amount > max {
         return Err(ContractError::InvalidAmount);
     }
     Ok(())
 }
 
 /// I7: Validate premium amount within bounds
-fn validate_premium_amount(premium: i128) -> Result<(), ContractError> {
-    if premium < MIN_PREMIUM_AMOUNT || premium > MAX_PREMIUM_AMOUNT {
+fn validate_premium_amount(env: &Env, premium: i128) -> Result<(), ContractError> {
+    let min = parameter_or(env, &POLICY_MIN_PREMIUM, MIN_PREMIUM_AMOUNT);
+    let max = parameter_or(env, &POLICY_MAX_PREMIUM, MAX_PREMIUM_AMOUNT);
+    if premium < min || premium > max {
         return Err(ContractError::InvalidPremium);
     }
     Ok(())
@@ -453,7 +504,7 @@ fn validate_duration(duration_days: u32) -> Result<(), ContractError> {
 impl PolicyContract {
     pub fn initialize(env: Env, admin: Address, risk_pool: Address) -> Result<(), ContractError> {
         // Check if already initialized
-        if insurance_contracts::authorization::get_admin(&env).is_some() {
+        if insurance_contracts::authorization::is_admin_initialized(&env) {
             return Err(ContractError::AlreadyInitialized);
         }
 
@@ -464,8 +515,9 @@ impl PolicyContract {
         admin.require_auth();
         initialize_admin(&env, admin.clone());
 
-        // Register risk pool contract as trusted for cross-contract calls
-        register_trusted_contract(&env, &admin, &risk_pool)?;
+        // Register risk pool contract as trusted for cross-contract calls. It has no fixed
+        // set of entry points on this contract, so the trust is deliberately unscoped.
+        register_unscoped_trust(&env, &admin, &risk_pool)?;
 
         let config = Config { risk_pool };
         env.storage().persistent().set(&DataKey::Config, &config);
@@ -498,10 +550,10 @@ impl PolicyContract {
         validate_address(&env, &holder)?;
 
         // Validate coverage amount within bounds
-        validate_coverage_amount(coverage_amount)?;
+        validate_coverage_amount(&env, coverage_amount)?;
 
         // Validate premium amount within bounds
-        validate_premium_amount(premium_amount)?;
+        validate_premium_amount(&env, premium_amount)?;
 
         // Validate duration within bounds
         validate_duration(duration_days)?;
@@ -599,7 +651,12 @@ impl PolicyContract {
 
     /// Cancels a policy. Only allowed when the policy is ACTIVE.
     pub fn cancel_policy(env: Env, actor: Address, policy_id: u64) -> Result<(), ContractError> {
-        require_admin(&env, &actor)?;
+        require_admin_action(
+            &env,
+            &actor,
+            &Symbol::new(&env, "cancel_policy"),
+            (policy_id,).into_val(&env),
+        )?;
 
         // Use the state machine to transition to CANCELLED
         PolicyStateMachine::transition(&env, policy_id, PolicyState::CANCELLED, actor)?;
@@ -609,7 +666,12 @@ impl PolicyContract {
 
     /// Expires a policy. Only allowed when the policy is ACTIVE.
     pub fn expire_policy(env: Env, actor: Address, policy_id: u64) -> Result<(), ContractError> {
-        require_admin(&env, &actor)?;
+        require_admin_action(
+            &env,
+            &actor,
+            &Symbol::new(&env, "expire_policy"),
+            (policy_id,).into_val(&env),
+        )?;
 
         // Use the state machine to transition to EXPIRED
         PolicyStateMachine::transition(&env, policy_id, PolicyState::EXPIRED, actor)?;
@@ -734,10 +796,214 @@ impl PolicyContract {
         is_paused(&env)
     }
 
+    /// Proposes `new_admin` as the next admin, to be accepted before `expires_at` (admin only).
+    pub fn propose_admin_transfer(
+        env: Env,
+        admin: Address,
+        new_admin: Address,
+        expires_at: u64,
+    ) -> Result<(), ContractError> {
+        validate_address(&env, &new_admin)?;
+        insurance_contracts::authorization::propose_admin_transfer(&env, &admin, &new_admin, expires_at)?;
+        Ok(())
+    }
+
+    /// Withdraws the pending admin transfer (admin only).
+    pub fn cancel_admin_transfer(env: Env, admin: Address) -> Result<(), ContractError> {
+        insurance_contracts::authorization::cancel_admin_transfer(&env, &admin)?;
+        Ok(())
+    }
+
+    /// Completes a pending admin transfer (proposed admin only).
+    pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), ContractError> {
+        insurance_contracts::authorization::accept_admin(&env, &new_admin)?;
+        Ok(())
+    }
+
+    pub fn get_pending_admin(env: Env) -> Option<PendingAdminTransfer> {
+        insurance_contracts::authorization::get_pending_admin(&env)
+    }
+
+    /// Gives up one of the caller's own roles. The admin must transfer `Admin` instead.
+    pub fn renounce_role(env: Env, caller: Address, role: Role) -> Result<(), ContractError> {
+        insurance_contracts::authorization::renounce_role(&env, &caller, role)?;
+        Ok(())
+    }
+
+    /// Switches this contract from the single admin to an M-of-N multisig admin (admin only).
+    pub fn enable_multisig_admin(
+        env: Env,
+        admin: Address,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<(), ContractError> {
+        insurance_contracts::authorization::enable_multisig_admin(&env, &admin, signers, threshold)?;
+
+        env.events().publish((Symbol::new(&env, "multisig_enabled"), ()), (admin, threshold));
+
+        Ok(())
+    }
+
+    /// Proposes an admin action to the multisig (signers only).
+    pub fn propose_admin_action(
+        env: Env,
+        signer: Address,
+        action: AdminAction,
+    ) -> Result<u64, ContractError> {
+        let proposal_id =
+            insurance_contracts::authorization::propose_admin_action(&env, &signer, action)?;
+
+        env.events().publish((Symbol::new(&env, "admin_action_proposed"), proposal_id), signer);
+
+        Ok(proposal_id)
+    }
+
+    /// Approves a pending multisig admin action (signers only).
+    pub fn approve_admin_action(
+        env: Env,
+        signer: Address,
+        proposal_id: u64,
+    ) -> Result<(), ContractError> {
+        insurance_contracts::authorization::approve_admin_action(&env, &signer, proposal_id)?;
+
+        env.events().publish((Symbol::new(&env, "admin_action_approved"), proposal_id), signer);
+
+        Ok(())
+    }
+
+    /// Executes a multisig admin action once the approval threshold is reached (signers only).
+    pub fn execute_admin_action(
+        env: Env,
+        signer: Address,
+        proposal_id: u64,
+    ) -> Result<(), ContractError> {
+        insurance_contracts::authorization::execute_admin_action(&env, &signer, proposal_id)?;
+
+        env.events().publish((Symbol::new(&env, "admin_action_executed"), proposal_id), signer);
+
+        Ok(())
+    }
+
+    pub fn get_admin_proposal(env: Env, proposal_id: u64) -> Result<AdminProposal, ContractError> {
+        insurance_contracts::authorization::get_admin_proposal(&env, proposal_id)
+            .ok_or(ContractError::NotFound)
+    }
+
+    pub fn get_multisig_config(env: Env) -> Option<MultisigConfig> {
+        insurance_contracts::authorization::get_multisig_config(&env)
+    }
+
+    /// Links the governance contract allowed to upgrade this contract (admin only, once).
+    /// The link cannot be repointed afterwards, so upgrades always go through that contract's votes.
+    pub fn set_governance_contract(
+        env: Env,
+        admin: Address,
+        governance: Address,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        if env.storage().persistent().has(&GOVERNANCE_CONTRACT) {
+            return Err(ContractError::AlreadyInitialized);
+        }
+        require_admin_action(
+            &env,
+            &admin,
+            &Symbol::new(&env, "set_governance_contract"),
+            (governance.clone(),).into_val(&env),
+        )?;
+        validate_address(&env, &governance)?;
+
+        env.storage().persistent().set(&GOVERNANCE_CONTRACT, &governance);
+
+        env.events().publish((Symbol::new(&env, "governance_set"), ()), governance);
+
+        Ok(())
+    }
+
+    /// Governance contract allowed to upgrade and emergency-pause this contract, if set.
+    pub fn get_governance_contract(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&GOVERNANCE_CONTRACT)
+    }
+
+    /// Whether `contract_address` is trusted for cross-contract calls.
+    pub fn is_trusted_contract(env: Env, contract_address: Address) -> bool {
+        insurance_contracts::authorization::is_trusted_contract(&env, &contract_address)
+    }
+
+    /// Pauses or unpauses the contract as an emergency council action.
+    /// Only callable by the governance contract; the pause is lifted again unless ratified.
+    pub fn set_emergency_pause(env: Env, paused: bool) -> Result<(), ContractError> {
+        let governance: Address = env
+            .storage()
+            .persistent()
+            .get(&GOVERNANCE_CONTRACT)
+            .ok_or(ContractError::NotInitialized)?;
+        governance.require_auth();
+
+        set_paused(&env, paused);
+
+        env.events().publish((Symbol::new(&env, "emergency_pause"), ()), paused);
+
+        Ok(())
+    }
+
+    /// Installs new wasm and migrates to `new_version`.
+    /// Only callable by the governance contract when executing a passed `ContractUpgrade` proposal.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>, new_version: u32) -> Result<(), ContractError> {
+        let governance: Address = env
+            .storage()
+            .persistent()
+            .get(&GOVERNANCE_CONTRACT)
+            .ok_or(ContractError::NotInitialized)?;
+        governance.require_auth();
+
+        UpgradeableContract::upgrade_wasm(
+            &env,
+            governance,
+            new_wasm_hash.clone(),
+            new_version,
+            default_migration_hook,
+        )
+        .map_err(map_versioning_error)?;
+
+        env.events().publish((Symbol::new(&env, "upgraded"), new_version), new_wasm_hash);
+
+        Ok(())
+    }
+
+    pub fn get_version(env: Env) -> Result<u32, ContractError> {
+        VersionManager::current_version(&env).map_err(map_versioning_error)
+    }
+
+    pub fn get_version_history(env: Env) -> Result<Vec<VersionTransition>, ContractError> {
+        VersionManager::version_history(&env).map_err(map_versioning_error)
+    }
+
+    /// Points the contract at the governance parameter registry (admin only).
+    pub fn set_parameter_registry(
+        env: Env,
+        admin: Address,
+        registry: Address,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin_action(
+            &env,
+            &admin,
+            &Symbol::new(&env, "set_parameter_registry"),
+            (registry.clone(),).into_val(&env),
+        )?;
+        validate_address(&env, &registry)?;
+
+        env.storage().persistent().set(&PARAM_REGISTRY, &registry);
+
+        env.events().publish((Symbol::new(&env, "parameter_registry_set"), ()), registry);
+
+        Ok(())
+    }
+
     pub fn pause(env: Env, admin: Address) -> Result<(), ContractError> {
         // Verify identity and require admin permission
         admin.require_auth();
-        require_admin(&env, &admin)?;
+        require_admin_action(&env, &admin, &Symbol::new(&env, "pause"), Vec::new(&env))?;
 
         set_paused(&env, true);
 
@@ -749,7 +1015,7 @@ impl PolicyContract {
     pub fn unpause(env: Env, admin: Address) -> Result<(), ContractError> {
         // Verify identity and require admin permission
         admin.require_auth();
-        require_admin(&env, &admin)?;
+        require_admin_action(&env, &admin, &Symbol::new(&env, "unpause"), Vec::new(&env))?;
 
         set_paused(&env, false);
 
@@ -758,20 +1024,21 @@ impl PolicyContract {
         Ok(())
     }
 
-    /// Grant policy manager role to an address (admin only)
+    /// Grant policy manager role to an address, optionally until `expires_at` (admin only)
     pub fn grant_manager_role(
         env: Env,
         admin: Address,
         manager: Address,
+        expires_at: Option<u64>,
     ) -> Result<(), ContractError> {
         admin.require_auth();
-        require_admin(&env, &admin)?;
 
         insurance_contracts::authorization::grant_role(
             &env,
             &admin,
             &manager,
             Role::PolicyManager,
+            expires_at,
         )?;
 
         env.events()
@@ -787,9 +1054,8 @@ impl PolicyContract {
         manager: Address,
     ) -> Result<(), ContractError> {
         admin.require_auth();
-        require_admin(&env, &admin)?;
 
-        insurance_contracts::authorization::revoke_role(&env, &admin, &manager)?;
+        insurance_contracts::authorization::revoke_role(&env, &admin, &manager, Role::PolicyManager)?;
 
         env.events()
             .publish((Symbol::new(&env, "role_revoked"), manager.clone()), admin);
@@ -801,6 +1067,26 @@ impl PolicyContract {
     pub fn get_user_role(env: Env, address: Address) -> Role {
         get_role(&env, &address)
     }
+
+    /// Get every role held by an address
+    pub fn get_user_roles(env: Env, address: Address) -> Vec<Role> {
+        insurance_contracts::authorization::get_roles(&env, &address)
+    }
+
+    /// Get the active role grants of an address with their expiry
+    pub fn get_role_grants(env: Env, address: Address) -> Vec<RoleGrant> {
+        insurance_contracts::authorization::get_role_grants(&env, &address)
+    }
+
+    /// Get the current holders of a role, paginated by list position (page size capped at 50)
+    pub fn get_role_members(env: Env, role: Role, start_index: u32, limit: u32) -> Vec<Address> {
+        insurance_contracts::authorization::get_role_members(&env, role, start_index, limit)
+    }
+
+    /// Get role grant/revoke/transfer history, oldest first, starting at entry `cursor`
+    pub fn get_role_audit_log(env: Env, cursor: u64, limit: u32) -> Vec<RoleAuditEntry> {
+        insurance_contracts::authorization::get_role_audit_log(&env, cursor, limit)
+    }
 }
 
 #[cfg(test)]
@@ -824,7 +1110,7 @@ mod tests {
             let risk_pool = Address::generate(&env);
 
             PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
-            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone(), None)
                 .unwrap();
 
             let coverage = MIN_COVERAGE_AMOUNT + 1000;
@@ -860,7 +1146,7 @@ mod tests {
             let risk_pool = Address::generate(&env);
 
             PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
-            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone(), None)
                 .unwrap();
 
             let result = PolicyContract::issue_policy(
@@ -886,7 +1172,7 @@ mod tests {
             let risk_pool = Address::generate(&env);
 
             PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
-            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone(), None)
                 .unwrap();
 
             let result = PolicyContract::issue_policy(
@@ -912,7 +1198,7 @@ mod tests {
             let risk_pool = Address::generate(&env);
 
             PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
-            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone(), None)
                 .unwrap();
 
             let result = PolicyContract::issue_policy(
@@ -938,7 +1224,7 @@ mod tests {
             let risk_pool = Address::generate(&env);
 
             PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
-            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone(), None)
                 .unwrap();
 
             let result = PolicyContract::issue_policy(
@@ -964,7 +1250,7 @@ mod tests {
             let risk_pool = Address::generate(&env);
 
             PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
-            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone(), None)
                 .unwrap();
 
             let result = PolicyContract::issue_policy(
@@ -990,7 +1276,7 @@ mod tests {
             let risk_pool = Address::generate(&env);
 
             PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
-            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone(), None)
                 .unwrap();
 
             let result = PolicyContract::issue_policy(
@@ -1018,7 +1304,7 @@ mod tests {
             let risk_pool = Address::generate(&env);
 
             PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
-            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone(), None)
                 .unwrap();
 
             let coverage = MIN_COVERAGE_AMOUNT + 1000;
@@ -1061,7 +1347,7 @@ mod tests {
             let risk_pool = Address::generate(&env);
 
             PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
-            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone(), None)
                 .unwrap();
 
             let coverage = MIN_COVERAGE_AMOUNT + 1000;
@@ -1103,7 +1389,7 @@ mod tests {
             let risk_pool = Address::generate(&env);
 
             PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
-            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone(), None)
                 .unwrap();
 
             let coverage = MIN_COVERAGE_AMOUNT + 1000;
@@ -1145,7 +1431,7 @@ mod tests {
             let risk_pool = Address::generate(&env);
 
             PolicyContract::initialize(env.clone(), admin.clone(), risk_pool.clone()).unwrap();
-            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone())
+            PolicyContract::grant_manager_role(env.clone(), admin.clone(), manager.clone(), None)
                 .unwrap();
 
             let coverage = MIN_COVERAGE_AMOUNT + 1000;
@@ -1,11 +1,18 @@
 #![no_std]
 use soroban_sdk::{contract, contracterror, contractimpl, Address, Env, Symbol};
-use soroban_sdk::{contract, contractimpl, contracterror, contracttype, Address, Env, Symbol};
+use soroban_sdk::{contract, contractimpl, contracterror, contracttype, Address, BytesN, Env, IntoVal, Symbol, Vec};
 
 // Import authorization from the common library
 use insurance_contracts::authorization::{
-    get_role, initialize_admin, register_trusted_contract, require_admin,
-    require_risk_pool_management, require_trusted_contract, Role,
+    get_role, initialize_admin, register_trusted_contract_for, require_admin_action,
+    require_risk_pool_management, require_trusted_contract_for, AdminAction, AdminProposal,
+    MultisigConfig, PendingAdminTransfer, Role, RoleAuditEntry, RoleGrant,
+};
+
+use insurance_contracts::parameters::{read_parameter, RISK_POOL_MIN_STAKE};
+use insurance_contracts::upgradeable::{
+    default_migration_hook, UpgradeableContract, VersionManager, VersionTransition,
+    VersioningError,
 };
 
 // Import invariant checks and error types
@@ -20,6 +27,8 @@ const POOL_STATS: Symbol = Symbol::short("POOL_ST");
 const PROVIDER: Symbol = Symbol::short("PROVIDER");
 const RESERVED_TOTAL: Symbol = Symbol::short("RSV_TOT");
 const CLAIM_RESERVATION: Symbol = Symbol::short("CLM_RSV");
+const PARAM_REGISTRY: Symbol = Symbol::short("PARAM_REG");
+const GOVERNANCE_CONTRACT: Symbol = Symbol::short("GOV_CON");
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -57,6 +66,16 @@ impl From<insurance_contracts::authorization::AuthError> for ContractError {
             insurance_contracts::authorization::AuthError::NotTrustedContract => {
                 ContractError::NotTrustedContract
             }
+            insurance_contracts::authorization::AuthError::InvalidInput => {
+                ContractError::InvalidInput
+            }
+            insurance_contracts::authorization::AuthError::NotFound => ContractError::NotFound,
+            insurance_contracts::authorization::AuthError::AlreadyExists => {
+                ContractError::AlreadyExists
+            }
+            insurance_contracts::authorization::AuthError::InvalidState => {
+                ContractError::InvalidState
+            }
         }
     }
 }
@@ -130,6 +149,29 @@ fn validate_amount(amount: i128) -> Result<(), ContractError> {
     Ok(())
 }
 
+fn map_versioning_error(e: VersioningError) -> ContractError {
+    match e {
+        VersioningError::NotInitialized => ContractError::NotInitialized,
+        VersioningError::MigrationInProgress => ContractError::Paused,
+        VersioningError::UnauthorizedUpgrade => ContractError::Unauthorized,
+        VersioningError::InvalidVersionNumber => ContractError::InvalidInput,
+        VersioningError::VersionMismatch
+        | VersioningError::MigrationFailed
+        | VersioningError::MigrationHookFailed
+        | VersioningError::SchemaValidationFailed
+        | VersioningError::RollbackFailed => ContractError::InvalidState,
+    }
+}
+
+/// Minimum provider stake, read live from the parameter registry when one is configured.
+fn min_provider_stake(env: &Env, configured: i128) -> i128 {
+    env.storage()
+        .persistent()
+        .get::<_, Address>(&PARAM_REGISTRY)
+        .and_then(|registry| read_parameter(env, &registry, &RISK_POOL_MIN_STAKE))
+        .unwrap_or(configured)
+}
+
 #[contractimpl]
 impl RiskPoolContract {
     pub fn initialize(
@@ -140,7 +182,7 @@ impl RiskPoolContract {
         claims_contract: Address,
     ) -> Result<(), ContractError> {
         // Check if already initialized
-        if insurance_contracts::authorization::get_admin(&env).is_some() {
+        if insurance_contracts::authorization::is_admin_initialized(&env) {
             return Err(ContractError::AlreadyInitialized);
         }
 
@@ -156,8 +198,11 @@ impl RiskPoolContract {
         admin.require_auth();
         initialize_admin(&env, admin.clone());
 
-        // Register claims contract as trusted for cross-contract calls
-        register_trusted_contract(&env, &admin, &claims_contract)?;
+        // Trust the claims contract for reservations and payouts only
+        let mut claims_functions = Vec::new(&env);
+        claims_functions.push_back(Symbol::new(&env, "reserve_liquidity"));
+        claims_functions.push_back(Symbol::new(&env, "payout_reserved_claim"));
+        register_trusted_contract_for(&env, &admin, &claims_contract, claims_functions)?;
 
         env.storage().persistent().set(&CONFIG, &(xlm_token, min_provider_stake));
 
@@ -192,7 +237,7 @@ impl RiskPoolContract {
             .get(&(PROVIDER, provider.clone()))
             .unwrap_or((0i128, 0i128, env.ledger().timestamp()));
 
-        if provider_info.1 + amount < config.1 {
+        if provider_info.1 + amount < min_provider_stake(&env, config.1) {
             return Err(ContractError::InvalidInput);
         }
 
@@ -313,7 +358,7 @@ impl RiskPoolContract {
     ) -> Result<(), ContractError> {
         // Verify that the caller is a trusted contract (e.g., claims contract)
         caller_contract.require_auth();
-        require_trusted_contract(&env, &caller_contract)?;
+        require_trusted_contract_for(&env, &caller_contract, &Symbol::new(&env, "reserve_liquidity"))?;
 
         if is_paused(&env) {
             return Err(ContractError::Paused);
@@ -362,7 +407,7 @@ impl RiskPoolContract {
     ) -> Result<(), ContractError> {
         // Verify that the caller is a trusted contract (e.g., claims contract)
         caller_contract.require_auth();
-        require_trusted_contract(&env, &caller_contract)?;
+        require_trusted_contract_for(&env, &caller_contract, &Symbol::new(&env, "payout_reserved_claim"))?;
 
         if is_paused(&env) {
             return Err(ContractError::Paused);
@@ -458,10 +503,218 @@ impl RiskPoolContract {
         Ok(())
     }
 
+    /// Proposes `new_admin` as the next admin, to be accepted before `expires_at` (admin only).
+    pub fn propose_admin_transfer(
+        env: Env,
+        admin: Address,
+        new_admin: Address,
+        expires_at: u64,
+    ) -> Result<(), ContractError> {
+        validate_address(&env, &new_admin)?;
+        insurance_contracts::authorization::propose_admin_transfer(&env, &admin, &new_admin, expires_at)?;
+        Ok(())
+    }
+
+    /// Withdraws the pending admin transfer (admin only).
+    pub fn cancel_admin_transfer(env: Env, admin: Address) -> Result<(), ContractError> {
+        insurance_contracts::authorization::cancel_admin_transfer(&env, &admin)?;
+        Ok(())
+    }
+
+    /// Completes a pending admin transfer (proposed admin only).
+    pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), ContractError> {
+        insurance_contracts::authorization::accept_admin(&env, &new_admin)?;
+        Ok(())
+    }
+
+    pub fn get_pending_admin(env: Env) -> Option<PendingAdminTransfer> {
+        insurance_contracts::authorization::get_pending_admin(&env)
+    }
+
+    /// Gives up one of the caller's own roles. The admin must transfer `Admin` instead.
+    pub fn renounce_role(env: Env, caller: Address, role: Role) -> Result<(), ContractError> {
+        insurance_contracts::authorization::renounce_role(&env, &caller, role)?;
+        Ok(())
+    }
+
+    /// Switches this contract from the single admin to an M-of-N multisig admin (admin only).
+    pub fn enable_multisig_admin(
+        env: Env,
+        admin: Address,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<(), ContractError> {
+        insurance_contracts::authorization::enable_multisig_admin(&env, &admin, signers, threshold)?;
+
+        env.events().publish((Symbol::new(&env, "multisig_enabled"), ()), (admin, threshold));
+
+        Ok(())
+    }
+
+    /// Proposes an admin action to the multisig (signers only).
+    pub fn propose_admin_action(
+        env: Env,
+        signer: Address,
+        action: AdminAction,
+    ) -> Result<u64, ContractError> {
+        let proposal_id =
+            insurance_contracts::authorization::propose_admin_action(&env, &signer, action)?;
+
+        env.events().publish((Symbol::new(&env, "admin_action_proposed"), proposal_id), signer);
+
+        Ok(proposal_id)
+    }
+
+    /// Approves a pending multisig admin action (signers only).
+    pub fn approve_admin_action(
+        env: Env,
+        signer: Address,
+        proposal_id: u64,
+    ) -> Result<(), ContractError> {
+        insurance_contracts::authorization::approve_admin_action(&env, &signer, proposal_id)?;
+
+        env.events().publish((Symbol::new(&env, "admin_action_approved"), proposal_id), signer);
+
+        Ok(())
+    }
+
+    /// Executes a multisig admin action once the approval threshold is reached (signers only).
+    pub fn execute_admin_action(
+        env: Env,
+        signer: Address,
+        proposal_id: u64,
+    ) -> Result<(), ContractError> {
+        insurance_contracts::authorization::execute_admin_action(&env, &signer, proposal_id)?;
+
+        env.events().publish((Symbol::new(&env, "admin_action_executed"), proposal_id), signer);
+
+        Ok(())
+    }
+
+    pub fn get_admin_proposal(env: Env, proposal_id: u64) -> Result<AdminProposal, ContractError> {
+        insurance_contracts::authorization::get_admin_proposal(&env, proposal_id)
+            .ok_or(ContractError::NotFound)
+    }
+
+    pub fn get_multisig_config(env: Env) -> Option<MultisigConfig> {
+        insurance_contracts::authorization::get_multisig_config(&env)
+    }
+
+    /// Links the governance contract allowed to upgrade this contract (admin only, once).
+    /// The link cannot be repointed afterwards, so upgrades always go through that contract's votes.
+    pub fn set_governance_contract(
+        env: Env,
+        admin: Address,
+        governance: Address,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        if env.storage().persistent().has(&GOVERNANCE_CONTRACT) {
+            return Err(ContractError::AlreadyInitialized);
+        }
+        require_admin_action(
+            &env,
+            &admin,
+            &Symbol::new(&env, "set_governance_contract"),
+            (governance.clone(),).into_val(&env),
+        )?;
+        validate_address(&env, &governance)?;
+
+        env.storage().persistent().set(&GOVERNANCE_CONTRACT, &governance);
+
+        env.events().publish((Symbol::new(&env, "governance_set"), ()), governance);
+
+        Ok(())
+    }
+
+    /// Governance contract allowed to upgrade and emergency-pause this contract, if set.
+    pub fn get_governance_contract(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&GOVERNANCE_CONTRACT)
+    }
+
+    /// Whether `contract_address` is trusted for cross-contract calls.
+    pub fn is_trusted_contract(env: Env, contract_address: Address) -> bool {
+        insurance_contracts::authorization::is_trusted_contract(&env, &contract_address)
+    }
+
+    pub fn get_admin(env: Env) -> Result<Address, ContractError> {
+        insurance_contracts::authorization::get_admin(&env).ok_or(ContractError::NotInitialized)
+    }
+
+    /// Pauses or unpauses the contract as an emergency council action.
+    /// Only callable by the governance contract; the pause is lifted again unless ratified.
+    pub fn set_emergency_pause(env: Env, paused: bool) -> Result<(), ContractError> {
+        let governance: Address = env
+            .storage()
+            .persistent()
+            .get(&GOVERNANCE_CONTRACT)
+            .ok_or(ContractError::NotInitialized)?;
+        governance.require_auth();
+
+        set_paused(&env, paused);
+
+        env.events().publish((Symbol::new(&env, "emergency_pause"), ()), paused);
+
+        Ok(())
+    }
+
+    /// Installs new wasm and migrates to `new_version`.
+    /// Only callable by the governance contract when executing a passed `ContractUpgrade` proposal.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>, new_version: u32) -> Result<(), ContractError> {
+        let governance: Address = env
+            .storage()
+            .persistent()
+            .get(&GOVERNANCE_CONTRACT)
+            .ok_or(ContractError::NotInitialized)?;
+        governance.require_auth();
+
+        UpgradeableContract::upgrade_wasm(
+            &env,
+            governance,
+            new_wasm_hash.clone(),
+            new_version,
+            default_migration_hook,
+        )
+        .map_err(map_versioning_error)?;
+
+        env.events().publish((Symbol::new(&env, "upgraded"), new_version), new_wasm_hash);
+
+        Ok(())
+    }
+
+    pub fn get_version(env: Env) -> Result<u32, ContractError> {
+        VersionManager::current_version(&env).map_err(map_versioning_error)
+    }
+
+    pub fn get_version_history(env: Env) -> Result<Vec<VersionTransition>, ContractError> {
+        VersionManager::version_history(&env).map_err(map_versioning_error)
+    }
+
+    /// Points the pool at the governance parameter registry (admin only).
+    pub fn set_parameter_registry(
+        env: Env,
+        admin: Address,
+        registry: Address,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin_action(
+            &env,
+            &admin,
+            &Symbol::new(&env, "set_parameter_registry"),
+            (registry.clone(),).into_val(&env),
+        )?;
+        validate_address(&env, &registry)?;
+
+        env.storage().persistent().set(&PARAM_REGISTRY, &registry);
+
+        env.events().publish((Symbol::new(&env, "parameter_registry_set"), ()), registry);
+
+        Ok(())
+    }
+
     pub fn pause(env: Env, admin: Address) -> Result<(), ContractError> {
         // Verify identity and require admin permission
         admin.require_auth();
-        require_admin(&env, &admin)?;
+        require_admin_action(&env, &admin, &Symbol::new(&env, "pause"), Vec::new(&env))?;
 
         set_paused(&env, true);
 
@@ -473,7 +726,7 @@ impl RiskPoolContract {
     pub fn unpause(env: Env, admin: Address) -> Result<(), ContractError> {
         // Verify identity and require admin permission
         admin.require_auth();
-        require_admin(&env, &admin)?;
+        require_admin_action(&env, &admin, &Symbol::new(&env, "unpause"), Vec::new(&env))?;
 
         set_paused(&env, false);
 
@@ -482,20 +735,21 @@ impl RiskPoolContract {
         Ok(())
     }
 
-    /// Grant risk pool manager role to an address (admin only)
+    /// Grant risk pool manager role to an address, optionally until `expires_at` (admin only)
     pub fn grant_manager_role(
         env: Env,
         admin: Address,
         manager: Address,
+        expires_at: Option<u64>,
     ) -> Result<(), ContractError> {
         admin.require_auth();
-        require_admin(&env, &admin)?;
 
         insurance_contracts::authorization::grant_role(
             &env,
             &admin,
             &manager,
             Role::RiskPoolManager,
+            expires_at,
         )?;
 
         env.events()
@@ -511,9 +765,8 @@ impl RiskPoolContract {
         manager: Address,
     ) -> Result<(), ContractError> {
         admin.require_auth();
-        require_admin(&env, &admin)?;
 
-        insurance_contracts::authorization::revoke_role(&env, &admin, &manager)?;
+        insurance_contracts::authorization::revoke_role(&env, &admin, &manager, Role::RiskPoolManager)?;
 
         env.events()
             .publish((Symbol::new(&env, "role_revoked"), manager.clone()), admin);
@@ -521,10 +774,56 @@ impl RiskPoolContract {
         Ok(())
     }
 
+    /// Trusts a contract for the listed entry points only, replacing any previous
+    /// allowlist (admin only)
+    pub fn register_trusted_contract_for(
+        env: Env,
+        admin: Address,
+        contract_address: Address,
+        functions: Vec<Symbol>,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        validate_address(&env, &contract_address)?;
+
+        register_trusted_contract_for(&env, &admin, &contract_address, functions.clone())?;
+
+        env.events().publish(
+            (Symbol::new(&env, "trusted_contract_scoped"), contract_address),
+            functions,
+        );
+
+        Ok(())
+    }
+
+    /// Entry points a trusted contract is limited to (`None` if unscoped or untrusted)
+    pub fn get_trusted_functions(env: Env, contract_address: Address) -> Option<Vec<Symbol>> {
+        insurance_contracts::authorization::get_trusted_functions(&env, &contract_address)
+    }
+
     /// Get the role of an address
     pub fn get_user_role(env: Env, address: Address) -> Role {
         get_role(&env, &address)
     }
+
+    /// Get every role held by an address
+    pub fn get_user_roles(env: Env, address: Address) -> Vec<Role> {
+        insurance_contracts::authorization::get_roles(&env, &address)
+    }
+
+    /// Get the active role grants of an address with their expiry
+    pub fn get_role_grants(env: Env, address: Address) -> Vec<RoleGrant> {
+        insurance_contracts::authorization::get_role_grants(&env, &address)
+    }
+
+    /// Get the current holders of a role, paginated by list position (page size capped at 50)
+    pub fn get_role_members(env: Env, role: Role, start_index: u32, limit: u32) -> Vec<Address> {
+        insurance_contracts::authorization::get_role_members(&env, role, start_index, limit)
+    }
+
+    /// Get role grant/revoke/transfer history, oldest first, starting at entry `cursor`
+    pub fn get_role_audit_log(env: Env, cursor: u64, limit: u32) -> Vec<RoleAuditEntry> {
+        insurance_contracts::authorization::get_role_audit_log(&env, cursor, limit)
+    }
 }
 
 #[cfg(test)]
@@ -911,6 +1210,39 @@ mod tests {
         assert_eq!(result, Err(ContractError::NotTrustedContract));
     }
 
+    #[test]
+    fn test_trusted_contract_limited_to_allowlisted_functions() {
+        let (env, admin, xlm_token, claims_contract) = setup_test_env();
+        initialize_pool(&env, &admin, &xlm_token, &claims_contract);
+
+        let provider = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
+
+        let mut functions = Vec::new(&env);
+        functions.push_back(Symbol::new(&env, "reserve_liquidity"));
+        RiskPoolContract::register_trusted_contract_for(
+            env.clone(),
+            admin.clone(),
+            claims_contract.clone(),
+            functions.clone(),
+        )
+        .unwrap();
+        assert_eq!(
+            RiskPoolContract::get_trusted_functions(env.clone(), claims_contract.clone()),
+            Some(functions)
+        );
+
+        RiskPoolContract::reserve_liquidity(env.clone(), claims_contract.clone(), 1, 3000).unwrap();
+        let result = RiskPoolContract::payout_reserved_claim(
+            env.clone(),
+            claims_contract.clone(),
+            1,
+            recipient.clone(),
+        );
+        assert_eq!(result, Err(ContractError::NotTrustedContract));
+    }
+
     #[test]
     fn test_reserve_liquidity_duplicate_claim_id() {
         let (env, admin, xlm_token, claims_contract) = setup_test_env();
@@ -1076,7 +1408,7 @@ mod tests {
         let manager = Address::generate(&env);
         let recipient = Address::generate(&env);
 
-        RiskPoolContract::grant_manager_role(env.clone(), admin.clone(), manager.clone()).unwrap();
+        RiskPoolContract::grant_manager_role(env.clone(), admin.clone(), manager.clone(), None).unwrap();
         RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
 
         let result = RiskPoolContract::payout_claim(
@@ -1102,7 +1434,7 @@ mod tests {
         let manager = Address::generate(&env);
         let recipient = Address::generate(&env);
 
-        RiskPoolContract::grant_manager_role(env.clone(), admin.clone(), manager.clone()).unwrap();
+        RiskPoolContract::grant_manager_role(env.clone(), admin.clone(), manager.clone(), None).unwrap();
         RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 5000).unwrap();
 
         let result = RiskPoolContract::payout_claim(
@@ -1124,7 +1456,7 @@ mod tests {
         let manager = Address::generate(&env);
         let recipient = Address::generate(&env);
 
-        RiskPoolContract::grant_manager_role(env.clone(), admin.clone(), manager.clone()).unwrap();
+        RiskPoolContract::grant_manager_role(env.clone(), admin.clone(), manager.clone(), None).unwrap();
         RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
 
         // Reserve 7000
@@ -1171,7 +1503,7 @@ mod tests {
         let manager = Address::generate(&env);
         let recipient = Address::generate(&env);
 
-        RiskPoolContract::grant_manager_role(env.clone(), admin.clone(), manager.clone()).unwrap();
+        RiskPoolContract::grant_manager_role(env.clone(), admin.clone(), manager.clone(), None).unwrap();
         RiskPoolContract::deposit_liquidity(env.clone(), provider.clone(), 10000).unwrap();
 
         let result = RiskPoolContract::payout_claim(
@@ -1228,6 +1560,7 @@ mod tests {
             env.clone(),
             admin.clone(),
             manager.clone(),
+            None,
         );
 
         assert!(result.is_ok());
@@ -1248,6 +1581,7 @@ mod tests {
             env.clone(),
             unauthorized.clone(),
             manager.clone(),
+            None,
         );
 
         assert_eq!(result, Err(ContractError::Unauthorized));
@@ -1264,6 +1598,7 @@ mod tests {
             env.clone(),
             admin.clone(),
             manager.clone(),
+            None,
         ).unwrap();
 
         let result = RiskPoolContract::revoke_manager_role(
@@ -1315,6 +1650,20 @@ mod tests {
         assert!(!is_paused(&env));
     }
 
+    #[test]
+    fn test_governance_link_cannot_be_repointed() {
+        let (env, admin, xlm_token, claims_contract) = setup_test_env();
+        initialize_pool(&env, &admin, &xlm_token, &claims_contract);
+
+        let governance = Address::generate(&env);
+        RiskPoolContract::set_governance_contract(env.clone(), admin.clone(), governance.clone()).unwrap();
+
+        let result =
+            RiskPoolContract::set_governance_contract(env.clone(), admin.clone(), Address::generate(&env));
+        assert_eq!(result, Err(ContractError::AlreadyInitialized));
+        assert_eq!(RiskPoolContract::get_governance_contract(env.clone()), Some(governance));
+    }
+
     // ============================================================
     // COMPLEX SCENARIO TESTS
     // ============================================================
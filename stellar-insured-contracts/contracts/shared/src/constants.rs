@@ -119,6 +119,9 @@ pub const MAX_ALLOCATION_PERCENT_PER_TX: u32 = 10;
 /// Treasury lock-up period (7 days)
 pub const TREASURY_LOCKUP_SECONDS: u64 = 7 * ONE_DAY_SECONDS;
 
+/// Length of a treasury budget epoch (30 days)
+pub const TREASURY_BUDGET_EPOCH_SECONDS: u64 = 30 * ONE_DAY_SECONDS;
+
 // ===== Decimals Constants =====
 
 /// Standard Stellar asset decimals
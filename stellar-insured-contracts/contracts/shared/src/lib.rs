@@ -10,6 +10,7 @@
 //! - `types` - Shared data types and enums (PolicyStatus, ClaimStatus, etc.)
 //! - `constants` - Configuration constants for validation and limits
 //! - `validation` - Reusable validation helper functions
+//! - `parameters` - Governance-controlled protocol parameter registry
 //!
 //! ## Usage
 //!
@@ -35,6 +36,7 @@ pub mod constants;
 pub mod validation;
 pub mod versioning;
 pub mod upgradeable;
+pub mod parameters;
 
 // Re-export commonly used types
 pub use errors::ContractError;
@@ -53,3 +55,7 @@ pub use versioning::{
     MigrationState, migration_state_to_u32, u32_to_migration_state,
 };
 pub use upgradeable::UpgradeableContract;
+pub use parameters::{
+    ParameterRegistry, ParameterError, ParameterKind, Parameter, ParameterChange,
+    read_parameter,
+};
@@ -0,0 +1,368 @@
+//! # Protocol Parameter Registry
+//!
+//! Protocol thresholds used to be scattered across contracts (oracle validation thresholds,
+//! the risk pool minimum provider stake, the treasury fee percentage, slashing penalty limits,
+//! policy coverage bounds).
+//! This module gives them a single home: every parameter is registered once with a key, a
+//! value kind, inclusive bounds and the contract that consumes it, and its value only changes
+//! when the governance contract executes a passed `ParameterChange` proposal.
+//!
+//! ## Storage Layout
+//!
+//! The registry lives in the governance contract's persistent storage:
+//!
+//! ```text
+//! (PARAM, key)      -> Parameter
+//! PARAM_KEY         -> Vec<Symbol> of registered keys
+//! (PARAM_HIS, key)  -> Vec<ParameterChange> (bounded, oldest entries dropped first)
+//! ```
+//!
+//! ## Usage Example
+//!
+//! ```rust,ignore
+//! use shared::parameters::{read_parameter, RISK_POOL_MIN_STAKE};
+//!
+//! // In a consuming contract, fall back to the locally configured value
+//! // when no registry is set or the key is not registered
+//! let min_stake = read_parameter(&env, &registry, &RISK_POOL_MIN_STAKE).unwrap_or(config.1);
+//! ```
+
+use soroban_sdk::{
+    contracterror, contracttype, symbol_short, Address, Env, IntoVal, Symbol, Vec,
+};
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Storage key prefix for parameter definitions
+const PARAM: Symbol = symbol_short!("PARAM");
+
+/// Storage key for the list of registered parameter keys
+const PARAM_KEYS: Symbol = symbol_short!("PARAM_KEY");
+
+/// Storage key prefix for per-parameter change history
+const PARAM_HISTORY: Symbol = symbol_short!("PARAM_HIS");
+
+/// Maximum number of changes kept per parameter (prevents unbounded growth)
+const MAX_PARAMETER_HISTORY: u32 = 100;
+
+/// Registry entry point used by consuming contracts to read live values
+const GET_PARAMETER_VALUE: &str = "get_parameter_value";
+
+// ===== Well-known parameter keys =====
+
+/// Treasury fee in basis points (owner: treasury)
+pub const TREASURY_FEE_BPS: Symbol = symbol_short!("TRS_FEE");
+
+/// Minimum stake a liquidity provider must keep in the risk pool (owner: risk pool)
+pub const RISK_POOL_MIN_STAKE: Symbol = symbol_short!("RP_MINSTK");
+
+/// Minimum number of oracle submissions for consensus (owner: oracle)
+pub const ORACLE_MIN_SUBMISSIONS: Symbol = symbol_short!("OR_MINSUB");
+
+/// Oracle consensus majority threshold in percent (owner: oracle)
+pub const ORACLE_MAJORITY_PERCENT: Symbol = symbol_short!("OR_MAJPCT");
+
+/// Oracle outlier deviation in percent (owner: oracle)
+pub const ORACLE_OUTLIER_DEVIATION_PERCENT: Symbol = symbol_short!("OR_OUTDEV");
+
+/// Maximum age of oracle data in seconds (owner: oracle)
+pub const ORACLE_STALENESS_SECONDS: Symbol = symbol_short!("OR_STALE");
+
+/// Minimum policy coverage amount (owner: policy)
+pub const POLICY_MIN_COVERAGE: Symbol = symbol_short!("PO_MINCOV");
+
+/// Maximum policy coverage amount (owner: policy)
+pub const POLICY_MAX_COVERAGE: Symbol = symbol_short!("PO_MAXCOV");
+
+/// Minimum policy premium amount (owner: policy)
+pub const POLICY_MIN_PREMIUM: Symbol = symbol_short!("PO_MINPRM");
+
+/// Maximum policy premium amount (owner: policy)
+pub const POLICY_MAX_PREMIUM: Symbol = symbol_short!("PO_MAXPRM");
+
+/// Ceiling on any configured slashing penalty, in percent (owner: slashing)
+pub const SLASHING_MAX_PENALTY_PERCENT: Symbol = symbol_short!("SL_MAXPCT");
+
+/// Ceiling on the repeat-offender penalty multiplier (owner: slashing)
+pub const SLASHING_MAX_REPEAT_MULTIPLIER: Symbol = symbol_short!("SL_MAXMUL");
+
+/// Minimum time between two slashings of the same target and role, in seconds (owner: slashing)
+pub const SLASHING_COOLDOWN_SECONDS: Symbol = symbol_short!("SL_COOLDN");
+
+/// Total voting power that quorum and objection shares are measured against (owner: governance)
+pub const GOVERNANCE_TOTAL_VOTING_POWER: Symbol = symbol_short!("GV_TOTVP");
+
+/// Refundable bond a proposer posts in the governance token (owner: governance)
+pub const GOVERNANCE_PROPOSAL_BOND: Symbol = symbol_short!("GV_BOND");
+
+/// Minimum governance token balance required to create a proposal (owner: governance)
+pub const GOVERNANCE_MIN_PROPOSER_POWER: Symbol = symbol_short!("GV_MINPWR");
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+/// Parameter registry errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ParameterError {
+    /// No parameter is registered under the key
+    NotFound = 1,
+
+    /// A parameter is already registered under the key
+    AlreadyRegistered = 2,
+
+    /// Bounds are inverted or outside what the parameter kind allows
+    InvalidBounds = 3,
+
+    /// Value lies outside the registered bounds
+    OutOfBounds = 4,
+}
+
+// ============================================================================
+// Type Definitions
+// ============================================================================
+
+/// How a parameter value is interpreted. All values are stored as `i128`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParameterKind {
+    /// Plain integer (counts, multipliers)
+    Integer = 0,
+
+    /// Token amount in the smallest unit
+    Amount = 1,
+
+    /// Basis points (0 - 10000)
+    BasisPoints = 2,
+
+    /// Percentage (0 - 100)
+    Percentage = 3,
+
+    /// Duration in seconds
+    Duration = 4,
+
+    /// Boolean flag (0 or 1)
+    Boolean = 5,
+}
+
+impl ParameterKind {
+    /// Natural bounds of the kind, tightened further by each parameter's own bounds
+    pub fn natural_bounds(&self) -> (i128, i128) {
+        match self {
+            ParameterKind::Integer | ParameterKind::Amount => (i128::MIN, i128::MAX),
+            ParameterKind::BasisPoints => (0, 10_000),
+            ParameterKind::Percentage => (0, 100),
+            ParameterKind::Duration => (0, u64::MAX as i128),
+            ParameterKind::Boolean => (0, 1),
+        }
+    }
+}
+
+/// A registered protocol parameter and its live value
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Parameter {
+    /// Registry key
+    pub key: Symbol,
+
+    /// How the value is interpreted
+    pub kind: ParameterKind,
+
+    /// Inclusive lower bound
+    pub min_value: i128,
+
+    /// Inclusive upper bound
+    pub max_value: i128,
+
+    /// Current value
+    pub value: i128,
+
+    /// Contract that consumes the parameter
+    pub owner: Address,
+
+    /// Timestamp of registration or of the last change
+    pub updated_at: u64,
+
+    /// Proposal that last changed the value (0 if never changed)
+    pub last_proposal_id: u64,
+}
+
+/// A single recorded change of a parameter value
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParameterChange {
+    /// Registry key
+    pub key: Symbol,
+
+    /// Value before the change
+    pub old_value: i128,
+
+    /// Value after the change
+    pub new_value: i128,
+
+    /// Governance proposal that authorized the change
+    pub proposal_id: u64,
+
+    /// Timestamp when the change was applied
+    pub changed_at: u64,
+}
+
+// ============================================================================
+// Parameter Registry
+// ============================================================================
+
+/// Storage-level registry operations, hosted by the governance contract
+///
+/// Authorization is the host contract's responsibility: registration is an admin
+/// operation and `apply_change` must only be reached from proposal execution.
+pub struct ParameterRegistry;
+
+impl ParameterRegistry {
+    /// Registers a new parameter with its bounds and initial value
+    ///
+    /// # Errors
+    ///
+    /// - `ParameterError::AlreadyRegistered` if the key is taken
+    /// - `ParameterError::InvalidBounds` if the bounds are inverted or exceed the kind
+    /// - `ParameterError::OutOfBounds` if the initial value is outside the bounds
+    pub fn register(
+        env: &Env,
+        key: &Symbol,
+        kind: ParameterKind,
+        min_value: i128,
+        max_value: i128,
+        initial_value: i128,
+        owner: &Address,
+    ) -> Result<Parameter, ParameterError> {
+        if env.storage().persistent().has(&(PARAM, key.clone())) {
+            return Err(ParameterError::AlreadyRegistered);
+        }
+
+        let (kind_min, kind_max) = kind.natural_bounds();
+        if min_value > max_value || min_value < kind_min || max_value > kind_max {
+            return Err(ParameterError::InvalidBounds);
+        }
+
+        if initial_value < min_value || initial_value > max_value {
+            return Err(ParameterError::OutOfBounds);
+        }
+
+        let parameter = Parameter {
+            key: key.clone(),
+            kind,
+            min_value,
+            max_value,
+            value: initial_value,
+            owner: owner.clone(),
+            updated_at: env.ledger().timestamp(),
+            last_proposal_id: 0,
+        };
+        env.storage().persistent().set(&(PARAM, key.clone()), &parameter);
+
+        let mut keys = Self::keys(env);
+        keys.push_back(key.clone());
+        env.storage().persistent().set(&PARAM_KEYS, &keys);
+
+        Ok(parameter)
+    }
+
+    /// Returns the parameter registered under `key`
+    pub fn get(env: &Env, key: &Symbol) -> Result<Parameter, ParameterError> {
+        env.storage()
+            .persistent()
+            .get(&(PARAM, key.clone()))
+            .ok_or(ParameterError::NotFound)
+    }
+
+    /// Returns all registered parameter keys in registration order
+    pub fn keys(env: &Env) -> Vec<Symbol> {
+        env.storage().persistent().get(&PARAM_KEYS).unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Checks that `value` is acceptable for the parameter registered under `key`
+    pub fn validate_value(env: &Env, key: &Symbol, value: i128) -> Result<(), ParameterError> {
+        let parameter = Self::get(env, key)?;
+        if value < parameter.min_value || value > parameter.max_value {
+            return Err(ParameterError::OutOfBounds);
+        }
+        Ok(())
+    }
+
+    /// Applies a value change authorized by `proposal_id` and records it in the history
+    pub fn apply_change(
+        env: &Env,
+        key: &Symbol,
+        new_value: i128,
+        proposal_id: u64,
+    ) -> Result<ParameterChange, ParameterError> {
+        Self::validate_value(env, key, new_value)?;
+
+        let mut parameter = Self::get(env, key)?;
+        let now = env.ledger().timestamp();
+
+        let change = ParameterChange {
+            key: key.clone(),
+            old_value: parameter.value,
+            new_value,
+            proposal_id,
+            changed_at: now,
+        };
+
+        parameter.value = new_value;
+        parameter.updated_at = now;
+        parameter.last_proposal_id = proposal_id;
+        env.storage().persistent().set(&(PARAM, key.clone()), &parameter);
+
+        let mut history = Self::history(env, key);
+        if history.len() >= MAX_PARAMETER_HISTORY {
+            history.pop_front();
+        }
+        history.push_back(change.clone());
+        env.storage().persistent().set(&(PARAM_HISTORY, key.clone()), &history);
+
+        Ok(change)
+    }
+
+    /// Returns the recorded changes of a parameter, oldest first
+    pub fn history(env: &Env, key: &Symbol) -> Vec<ParameterChange> {
+        env.storage()
+            .persistent()
+            .get(&(PARAM_HISTORY, key.clone()))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+}
+
+// ============================================================================
+// Consumer Helpers
+// ============================================================================
+
+/// Reads the live value of `key` from the registry hosted at `registry`
+///
+/// Returns `None` if the call fails or the key is not registered, so consumers can
+/// fall back to their locally configured value.
+pub fn read_parameter(env: &Env, registry: &Address, key: &Symbol) -> Option<i128> {
+    match env.try_invoke_contract::<i128, soroban_sdk::Error>(
+        registry,
+        &Symbol::new(env, GET_PARAMETER_VALUE),
+        (key.clone(),).into_val(env),
+    ) {
+        Ok(Ok(value)) => Some(value),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_natural_bounds() {
+        assert_eq!(ParameterKind::BasisPoints.natural_bounds(), (0, 10_000));
+        assert_eq!(ParameterKind::Percentage.natural_bounds(), (0, 100));
+        assert_eq!(ParameterKind::Boolean.natural_bounds(), (0, 1));
+        assert_eq!(ParameterKind::Duration.natural_bounds().0, 0);
+    }
+}
@@ -9,6 +9,8 @@
 //! 1. Initialize with `UpgradeableContract::initialize()`
 //! 2. Check version compatibility at the start of each function with `ensure_version_compatible()`
 //! 3. Implement custom migration logic as needed
+//! 4. Expose an `upgrade(new_wasm_hash, new_version)` entry point that authenticates the
+//!    governance contract and calls `UpgradeableContract::upgrade_wasm()`
 //!
 //! ## Storage Safety
 //!
@@ -21,7 +23,7 @@
 //! Application data should use different keys to avoid conflicts.
 
 use crate::versioning::{VersionManager, VersioningError, MigrationState};
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, BytesN, Env};
 
 // ============================================================================
 // Upgradeable Contract Base
@@ -149,6 +151,61 @@ impl UpgradeableContract {
         VersionManager::migrate(env, current_version, new_version, migrator, hook)
     }
 
+    /// Replaces the contract wasm after running the migration hook
+    ///
+    /// Initializes versioning at version 1 for contracts deployed before versioning was
+    /// introduced, migrates to `new_version` (recording a `VersionTransition` attributed to
+    /// `migrator`) and then swaps the executable. The hook runs in the current code; the new
+    /// wasm takes effect once the invocation completes.
+    ///
+    /// Authorization is the caller's responsibility: contracts must only reach this after
+    /// authenticating the governance contract executing a passed `ContractUpgrade` proposal.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - Soroban environment
+    /// * `migrator` - Authenticated address performing the upgrade
+    /// * `new_wasm_hash` - Hash of the already uploaded wasm to switch to
+    /// * `new_version` - Target version (must be > current)
+    /// * `hook` - Migration logic registered by the contract
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>, new_version: u32) -> Result<(), ContractError> {
+    ///     let governance = get_governance_contract(&env)?;
+    ///     governance.require_auth();
+    ///
+    ///     UpgradeableContract::upgrade_wasm(&env, governance, new_wasm_hash, new_version, migration_hook)
+    ///         .map_err(map_versioning_error)
+    /// }
+    /// ```
+    pub fn upgrade_wasm<F>(
+        env: &Env,
+        migrator: Address,
+        new_wasm_hash: BytesN<32>,
+        new_version: u32,
+        hook: F,
+    ) -> Result<(), VersioningError>
+    where
+        F: Fn(&Env) -> Result<(), VersioningError>,
+    {
+        let current_version = match VersionManager::current_version(env) {
+            Ok(version) => version,
+            Err(VersioningError::NotInitialized) => {
+                VersionManager::initialize(env, 1)?;
+                1
+            }
+            Err(e) => return Err(e),
+        };
+
+        VersionManager::migrate(env, current_version, new_version, migrator, hook)?;
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+
+        Ok(())
+    }
+
     /// Gets the current contract version
     pub fn current_version(env: &Env) -> Result<u32, VersioningError> {
         VersionManager::current_version(env)
@@ -1,18 +1,32 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracterror, Address, Env, Symbol, Vec};
+use shared::parameters::{
+    read_parameter, SLASHING_COOLDOWN_SECONDS, SLASHING_MAX_PENALTY_PERCENT,
+    SLASHING_MAX_REPEAT_MULTIPLIER,
+};
+use soroban_sdk::{contract, contractimpl, contracterror, symbol_short, Address, BytesN, Env, Symbol, Vec};
 
 #[contract]
 pub struct SlashingContract;
 
-const ADMIN: Symbol = Symbol::short("ADMIN");
-const PAUSED: Symbol = Symbol::short("PAUSED");
-const CONFIG: Symbol = Symbol::short("CONFIG");
-const SLASHING_RECORD: Symbol = Symbol::short("SLASH_REC");
-const SLASHABLE_ROLES: Symbol = Symbol::short("SLASH_RL");
-const PENALTY_PARAMS: Symbol = Symbol::short("PENALTY");
-const SLASH_COUNTER: Symbol = Symbol::short("SLASH_CNT");
-const GOVERNANCE_CONTRACT: Symbol = Symbol::short("GOV_CON");
-const RISK_POOL_CONTRACT: Symbol = Symbol::short("RISK_PO");
+const ADMIN: Symbol = symbol_short!("ADMIN");
+const PAUSED: Symbol = symbol_short!("PAUSED");
+const CONFIG: Symbol = symbol_short!("CONFIG");
+const SLASHING_RECORD: Symbol = symbol_short!("SLASH_REC");
+const SLASHABLE_ROLES: Symbol = symbol_short!("SLASH_RL");
+const PENALTY_PARAMS: Symbol = symbol_short!("PENALTY");
+const SLASH_COUNTER: Symbol = symbol_short!("SLASH_CNT");
+const GOVERNANCE_CONTRACT: Symbol = symbol_short!("GOV_CON");
+const RISK_POOL_CONTRACT: Symbol = symbol_short!("RISK_PO");
+const CONTRACT_VERSION: Symbol = symbol_short!("VERSION");
+const VERSION_HISTORY: Symbol = symbol_short!("VERS_HIS");
+const PARAM_REGISTRY: Symbol = symbol_short!("PARAM_REG");
+
+/// Cooldown between slashings of the same target and role when the registry has none
+const DEFAULT_COOLDOWN_SECONDS: u64 = 86400;
+
+/// Version transition: (from_version, to_version, migrated_by, migration_timestamp).
+/// Mirrors `shared::versioning::VersionTransition` in the tuple layout existing records use.
+pub type VersionTransition = (u32, u32, Address, u64);
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub enum SlashingRole {
@@ -116,6 +130,34 @@ fn require_governance_or_admin(env: &Env) -> Result<(), ContractError> {
     Ok(())
 }
 
+fn current_version(env: &Env) -> u32 {
+    // Contracts deployed before versioning are treated as version 1
+    env.storage()
+        .persistent()
+        .get(&CONTRACT_VERSION)
+        .unwrap_or(1)
+}
+
+/// Storage migration run before the wasm is replaced. No layout changes yet.
+fn run_migration_hook(_env: &Env, _from_version: u32, _to_version: u32) -> Result<(), ContractError> {
+    Ok(())
+}
+
+/// Reads a live value from the governance parameter registry, if one is configured
+fn registry_parameter(env: &Env, key: &Symbol) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get::<_, Address>(&PARAM_REGISTRY)
+        .and_then(|registry| read_parameter(env, &registry, key))
+}
+
+/// Limits a configured penalty setting to the registry ceiling under `key`, if any
+fn capped_by_registry(env: &Env, key: &Symbol, configured: u32) -> u32 {
+    registry_parameter(env, key)
+        .and_then(|ceiling| u32::try_from(ceiling).ok())
+        .map_or(configured, |ceiling| configured.min(ceiling))
+}
+
 fn is_role_slashable(env: &Env, role: u32) -> bool {
     let slashable_roles: Vec<u32> = env
         .storage()
@@ -149,7 +191,9 @@ fn has_recent_slashing(env: &Env, target: &Address, role: u32, current_time: u64
         .get(&(SLASHING_RECORD, target, role))
         .unwrap_or_else(|| Vec::new(&env));
     
-    let cooldown_period = 86400u64; // 24 hours in seconds
+    let cooldown_period = registry_parameter(env, &SLASHING_COOLDOWN_SECONDS)
+        .and_then(|value| u64::try_from(value).ok())
+        .unwrap_or(DEFAULT_COOLDOWN_SECONDS);
     
     for record in slashing_records.iter() {
         if current_time - record.4 < cooldown_period {
@@ -241,6 +285,14 @@ impl SlashingContract {
         Ok(())
     }
 
+    /// Point the contract at the governance parameter registry (admin only)
+    pub fn set_parameter_registry(env: Env, registry: Address) -> Result<(), ContractError> {
+        require_admin(&env)?;
+        validate_address(&env, &registry)?;
+        env.storage().persistent().set(&PARAM_REGISTRY, &registry);
+        Ok(())
+    }
+
     pub fn add_slashable_role(env: Env, role: u32) -> Result<(), ContractError> {
         require_admin(&env)?;
 
@@ -319,9 +371,9 @@ impl SlashingContract {
         let violation_count = Self::get_violation_count(env.clone(), target.clone(), role)?;
         let penalty_amount = calculate_penalty_amount(
             base_amount,
-            penalty_params.2,
+            capped_by_registry(&env, &SLASHING_MAX_PENALTY_PERCENT, penalty_params.2),
             violation_count,
-            penalty_params.4,
+            capped_by_registry(&env, &SLASHING_MAX_REPEAT_MULTIPLIER, penalty_params.4),
         )?;
 
         let slash_id: u64 = env
@@ -490,10 +542,85 @@ impl SlashingContract {
         Ok(admin)
     }
 
+    /// Governance contract allowed to slash and emergency-pause this contract.
+    pub fn get_governance_contract(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&GOVERNANCE_CONTRACT)
+    }
+
     pub fn is_contract_paused(env: Env) -> bool {
         is_paused(&env)
     }
 
+    /// Pauses or unpauses the contract as an emergency council action.
+    /// Only callable by the governance contract; the pause is lifted again unless ratified.
+    pub fn set_emergency_pause(env: Env, paused: bool) -> Result<(), ContractError> {
+        let governance: Address = env
+            .storage()
+            .persistent()
+            .get(&GOVERNANCE_CONTRACT)
+            .ok_or(ContractError::NotInitialized)?;
+        governance.require_auth();
+
+        set_paused(&env, paused);
+
+        env.events().publish((Symbol::new(&env, "emergency_pause"), ()), paused);
+
+        Ok(())
+    }
+
+    /// Installs new wasm and migrates to `new_version`.
+    /// Only callable by the governance contract when executing a passed `ContractUpgrade` proposal.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>, new_version: u32) -> Result<(), ContractError> {
+        let governance_contract: Address = env
+            .storage()
+            .persistent()
+            .get(&GOVERNANCE_CONTRACT)
+            .ok_or(ContractError::NotInitialized)?;
+        governance_contract.require_auth();
+
+        let from_version = current_version(&env);
+        if new_version <= from_version {
+            return Err(ContractError::InvalidInput);
+        }
+
+        run_migration_hook(&env, from_version, new_version)?;
+
+        let mut history: Vec<VersionTransition> = env
+            .storage()
+            .persistent()
+            .get(&VERSION_HISTORY)
+            .unwrap_or_else(|| Vec::new(&env));
+        history.push_front((
+            from_version,
+            new_version,
+            governance_contract,
+            env.ledger().timestamp(),
+        ));
+
+        env.storage().persistent().set(&CONTRACT_VERSION, &new_version);
+        env.storage().persistent().set(&VERSION_HISTORY, &history);
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+
+        env.events().publish(
+            (Symbol::new(&env, "upgraded"), new_version),
+            (from_version, new_wasm_hash),
+        );
+
+        Ok(())
+    }
+
+    pub fn get_version(env: Env) -> u32 {
+        current_version(&env)
+    }
+
+    pub fn get_version_history(env: Env) -> Vec<VersionTransition> {
+        env.storage()
+            .persistent()
+            .get(&VERSION_HISTORY)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
     pub fn get_slashing_stats(env: Env) -> Result<(u64, u64, i128), ContractError> {
         let slash_count: u64 = env
             .storage()
@@ -513,6 +640,7 @@ impl SlashingContract {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use soroban_sdk::testutils::Address as _;
     use soroban_sdk::{Address, Env};
 
     #[test]
@@ -571,4 +699,48 @@ mod tests {
         let repeat_penalty_2 = expected_penalty * repeat_multiplier_2 as i128;
         assert_eq!(repeat_penalty_2, 1500i128); // Second offense (1 + (2-1)*2 = 3, 500*3 = 1500)
     }
+
+    #[test]
+    fn test_registry_caps_penalty_percentage() {
+        let env = Env::default();
+        let contract_id = env.register(SlashingContract, ());
+        let registry = env.register(registry::MockRegistry, ());
+        let target = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            SlashingContract::initialize(
+                env.clone(),
+                contract_id.clone(),
+                Address::generate(&env),
+                Address::generate(&env),
+            )
+            .unwrap();
+            SlashingContract::configure_penalty_parameters(env.clone(), 0, 0, 50, 0, 2, 86400).unwrap();
+            SlashingContract::set_parameter_registry(env.clone(), registry.clone()).unwrap();
+
+            SlashingContract::slash_funds(env.clone(), target.clone(), 0, 0, 1000).unwrap();
+
+            // 20% registry ceiling applies instead of the configured 50%
+            let history = SlashingContract::get_slashing_history(env.clone(), target.clone(), 0).unwrap();
+            assert_eq!(history.get(0).unwrap().5, 200);
+        });
+    }
+
+    mod registry {
+        use shared::parameters::SLASHING_MAX_PENALTY_PERCENT;
+        use soroban_sdk::{contract, contractimpl, Env, Symbol};
+
+        #[contract]
+        pub struct MockRegistry;
+
+        #[contractimpl]
+        impl MockRegistry {
+            pub fn get_parameter_value(_env: Env, key: Symbol) -> i128 {
+                if key != SLASHING_MAX_PENALTY_PERCENT {
+                    panic!("parameter not registered");
+                }
+                20
+            }
+        }
+    }
 }
@@ -1,17 +1,23 @@
 #![no_std]
-use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, Address, Env, Map, Symbol, Vec,
-};
-
 // Import authorization from the common library
 use insurance_contracts::authorization::{
-    get_role, initialize_admin, register_trusted_contract, require_admin,
-    require_governance_permission, Role,
+    get_role, initialize_admin, require_admin,
+    require_governance_permission, PendingAdminTransfer, Role, RoleAuditEntry,
+};
+use insurance_contracts::constants::{
+    MAX_ALLOCATION_PERCENT_PER_TX, MIN_ALLOCATION_AMOUNT, TREASURY_BUDGET_EPOCH_SECONDS,
+    TREASURY_LOCKUP_SECONDS,
+};
+use insurance_contracts::parameters::{read_parameter, TREASURY_FEE_BPS};
+use insurance_contracts::upgradeable::{
+    default_migration_hook, UpgradeableContract, VersionManager, VersionTransition,
+    VersioningError,
 };
 // Import invariant checks
 use insurance_invariants::{InvariantError, ProtocolInvariants};
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, Address, Env, Map, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, token, Address, BytesN, Env, Map, Symbol,
+    Val, Vec,
 };
 
 // ============================================================================
@@ -27,6 +33,32 @@ const ALLOCATIONS: Symbol = Symbol::short("ALLOC");
 const TOTAL_FEES_COLLECTED: Symbol = Symbol::short("TOTAL_FEE");
 const TOTAL_WITHDRAWN: Symbol = Symbol::short("TOTAL_WIT");
 const TRUSTED_CONTRACTS: Symbol = Symbol::short("TRUST_CON");
+const SUPPORTED_ASSETS: Symbol = Symbol::short("ASSETS");
+const RESERVED_BALANCE: Symbol = Symbol::short("RESERVED");
+const STREAMS: Symbol = Symbol::short("STREAMS");
+const STREAM_SCHEDULES: Symbol = Symbol::short("STRM_SCHD");
+const PURPOSE_BUDGETS: Symbol = Symbol::short("BUDGET");
+const BUDGET_SPENT: Symbol = Symbol::short("BUDG_SPNT");
+const FEE_COUNTER: Symbol = Symbol::short("FEE_CNT");
+const FEE_DEPOSITS: Symbol = Symbol::short("FEE_DEP");
+const FEES_BY_TYPE: Symbol = Symbol::short("FEE_TYPE");
+const FEES_BY_SOURCE: Symbol = Symbol::short("FEE_SRC");
+const PROPOSAL_INDEX: Symbol = Symbol::short("PROP_IDX");
+const STATUS_COUNT: Symbol = Symbol::short("STAT_CNT");
+const INDEX_MIGRATION: Symbol = Symbol::short("IDX_MIGR");
+const ACTIVE_PROPOSALS: Symbol = Symbol::short("ACT_PROP");
+const COMPLETED_PROPOSALS: Symbol = Symbol::short("DONE_PROP");
+const ALLOCATION_COUNT: Symbol = Symbol::short("ALLOC_CNT");
+
+/// Maximum number of records returned by a single paginated query
+const MAX_PAGINATION_LIMIT: u32 = 50;
+
+/// Proposal ids per status index bucket; bucket `n` holds ids in `[n * size, (n + 1) * size)`
+const STATUS_BUCKET_SIZE: u64 = 64;
+
+/// Storage layout version. Version 1 held a single unnamed asset: one balance and fee/withdrawal
+/// totals without an asset key, and allocations and proposals without an asset field.
+const STORAGE_VERSION: u32 = 2;
 
 // ============================================================================
 // Error Handling
@@ -53,6 +85,12 @@ pub enum ContractError {
     ProposalNotActive = 16,
     QuorumNotMet = 17,
     ThresholdNotMet = 18,
+    UnsupportedAsset = 19,
+    NothingToClaim = 20,
+    AllocationTooSmall = 21,
+    AllocationCapExceeded = 22,
+    BudgetExceeded = 23,
+    LockupPeriodActive = 24,
     // Invariant violation errors (100-199)
     InvalidAmount = 103,
     BalanceViolation = 100,
@@ -74,20 +112,20 @@ impl From<insurance_contracts::authorization::AuthError> for ContractError {
             insurance_contracts::authorization::AuthError::NotTrustedContract => {
                 ContractError::NotTrustedContract
             }
+            insurance_contracts::authorization::AuthError::InvalidInput => {
+                ContractError::InvalidInput
+            }
+            insurance_contracts::authorization::AuthError::NotFound => ContractError::NotFound,
+            insurance_contracts::authorization::AuthError::AlreadyExists => {
+                ContractError::AlreadyExists
+            }
+            insurance_contracts::authorization::AuthError::InvalidState => {
+                ContractError::InvalidState
+            }
         }
     }
 }
 
-// ============================================================================
-// Small helpers for Soroban SDK v25
-// ============================================================================
-
-/// In SDK v25 there is no `env.invoker()`. For our purposes, we treat the
-/// contract "caller" as the current invoker address.
-fn invoker(env: &Env) -> Address {
-    env.current_contract_address()
-}
-
 impl From<InvariantError> for ContractError {
     fn from(err: InvariantError) -> Self {
         match err {
@@ -129,59 +167,166 @@ pub enum AllocationPurpose {
 pub struct TreasuryConfig {
     pub admin: Address,
     pub governance_contract: Address,
+    pub token: Address, // Primary asset (Stellar Asset Contract), supported from initialization
     pub fee_percentage: u32, // Fee percentage in basis points (e.g., 500 = 5%)
 }
 
-/// Fee deposit record
+/// Fee deposit record, kept for every deposit in fee_id order
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct FeeDeposit {
     pub fee_id: u64,
     pub fee_type: u32, // FeeType enum
+    pub asset: Address,
     pub amount: i128,
     pub depositor: Address,
     pub timestamp: u64,
     pub source_contract: Address,
 }
 
+/// Page of fee deposits returned by `get_fee_deposits`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeDepositPage {
+    pub deposits: Vec<FeeDeposit>,
+    pub next_cursor: u64, // fee_id to continue from; 0 when the range is exhausted
+}
+
 /// Withdrawal proposal for DAO governance
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct WithdrawalProposal {
     pub proposal_id: u64,
     pub recipient: Address,
+    pub asset: Address,
     pub amount: i128,
     pub purpose: u32, // AllocationPurpose enum
     pub description: Symbol,
     pub proposed_by: Address,
     pub created_at: u64,
     pub voting_ends_at: u64,
-    pub yes_votes: i128,
+    pub governance_proposal_id: u64, // TreasuryAllocation proposal that approved it (0 until approved)
+    pub yes_votes: i128,             // Tally of the approving governance proposal
     pub no_votes: i128,
-    pub status: u32, // ProposalStatus enum: 0=Active, 1=Approved, 2=Rejected, 3=Executed
+    pub approved_at: u64, // Executable once TREASURY_LOCKUP_SECONDS have passed (0 until approved)
+    pub status: u32,      // ProposalStatus enum: 0=Active, 1=Approved, 2=Rejected, 3=Executed
     pub executed: bool,
+    pub streamed: bool, // Paid out as a vesting stream instead of a lump sum
+}
+
+/// Vesting timeline of a streamed withdrawal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingSchedule {
+    pub start: u64, // Vesting accrues linearly from here
+    pub cliff: u64, // Nothing can be claimed before this
+    pub end: u64,   // Fully vested from here
+}
+
+/// Streaming allocation created when a withdrawal with a schedule is executed.
+/// The undisbursed part of `total` stays reserved in the treasury until claimed or cancelled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Stream {
+    pub stream_id: u64, // Id of the withdrawal proposal that created it
+    pub recipient: Address,
+    pub asset: Address,
+    pub purpose: u32, // AllocationPurpose enum
+    pub total: i128,  // Reduced to the vested amount on cancellation
+    pub claimed: i128,
+    pub schedule: VestingSchedule,
+    pub cancelled: bool,
 }
 
-/// Allocation tracking per purpose
+/// Allocation tracking per asset and purpose
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct AllocationRecord {
+    pub asset: Address,
     pub purpose: u32, // AllocationPurpose enum
     pub total_allocated: i128,
     pub total_withdrawn: i128,
     pub allocation_count: u64,
 }
 
-/// Treasury statistics
+/// Spending against a purpose's budget in an asset during the current epoch
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BudgetUtilization {
+    pub asset: Address,
+    pub purpose: u32, // AllocationPurpose enum
+    pub epoch: u64,   // timestamp / TREASURY_BUDGET_EPOCH_SECONDS
+    pub budget: i128, // 0 when the purpose has no budget and is only bound by the per-tx cap
+    pub spent: i128,
+    pub remaining: i128,
+}
+
+/// Treasury statistics; amounts and counts cover a single asset
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TreasuryStats {
     pub total_fees_collected: i128,
     pub total_balance: i128,
     pub total_withdrawn: i128,
-    pub active_proposals: u64,
-    pub completed_proposals: u64,
-    pub total_allocations: u64,
+    pub active_proposals: u64,    // Awaiting approval or execution
+    pub completed_proposals: u64, // Executed or rejected
+    pub total_allocations: u64,   // Executed withdrawals, lump sum or streamed
+}
+
+/// Result of a paginated withdrawal proposals query
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalProposalPage {
+    pub proposals: Vec<WithdrawalProposal>,
+    pub total_count: u32,  // Number of proposals with the requested status
+    pub next_cursor: u64, // proposal_id to continue from; 0 when the listing is exhausted
+}
+
+/// Treasury config layout used by storage version 1, before the treasury held a configured token
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct LegacyTreasuryConfig {
+    pub admin: Address,
+    pub governance_contract: Address,
+    pub fee_percentage: u32,
+}
+
+/// Withdrawal proposal layout used by storage version 1
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct LegacyWithdrawalProposal {
+    pub proposal_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    pub purpose: u32,
+    pub description: Symbol,
+    pub proposed_by: Address,
+    pub created_at: u64,
+    pub voting_ends_at: u64,
+    pub yes_votes: i128,
+    pub no_votes: i128,
+    pub status: u32,
+    pub executed: bool,
+}
+
+/// Allocation record layout used by storage version 1, keyed by purpose only
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct LegacyAllocationRecord {
+    pub purpose: u32,
+    pub total_allocated: i128,
+    pub total_withdrawn: i128,
+    pub allocation_count: u64,
+}
+
+/// Internal ledger balance of an asset compared against the tokens the contract actually holds
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BalanceReconciliation {
+    pub asset: Address,
+    pub ledger_balance: i128,
+    pub token_balance: i128,
+    pub difference: i128, // token_balance - ledger_balance; positive means unaccounted surplus
 }
 
 /// ============================================================================
@@ -195,6 +340,114 @@ pub struct TreasuryContract;
 // Helper Functions
 // ============================================================================
 
+fn map_versioning_error(e: VersioningError) -> ContractError {
+    match e {
+        VersioningError::NotInitialized => ContractError::NotInitialized,
+        VersioningError::MigrationInProgress => ContractError::Paused,
+        VersioningError::UnauthorizedUpgrade => ContractError::Unauthorized,
+        VersioningError::InvalidVersionNumber => ContractError::InvalidInput,
+        VersioningError::VersionMismatch
+        | VersioningError::MigrationFailed
+        | VersioningError::MigrationHookFailed
+        | VersioningError::SchemaValidationFailed
+        | VersioningError::RollbackFailed => ContractError::InvalidState,
+    }
+}
+
+/// Move a single-asset value of storage version 1 to its key under `token`
+fn migrate_asset_total(env: &Env, key: Symbol, token: &Address) {
+    if let Some(amount) = env.storage().persistent().get::<_, i128>(&key) {
+        env.storage().persistent().set(&(key.clone(), token), &amount);
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Migration from storage version 1 to 2: records `token` as the treasury's asset and moves
+/// the balance, fee and withdrawal totals and the allocation records under it.
+/// Proposals are rewritten in batches by `migrate_proposal_index`.
+fn migrate_v1_to_v2(env: &Env, token: &Address) -> Result<(), VersioningError> {
+    let legacy: LegacyTreasuryConfig = env
+        .storage()
+        .persistent()
+        .get(&CONFIG)
+        .ok_or(VersioningError::SchemaValidationFailed)?;
+
+    let config = TreasuryConfig {
+        admin: legacy.admin,
+        governance_contract: legacy.governance_contract,
+        token: token.clone(),
+        fee_percentage: legacy.fee_percentage,
+    };
+    env.storage().persistent().set(&CONFIG, &config);
+
+    let mut assets = Vec::new(env);
+    assets.push_back(token.clone());
+    env.storage().persistent().set(&SUPPORTED_ASSETS, &assets);
+
+    migrate_asset_total(env, TREASURY_BALANCE, token);
+    migrate_asset_total(env, TOTAL_FEES_COLLECTED, token);
+    migrate_asset_total(env, TOTAL_WITHDRAWN, token);
+
+    for purpose in
+        AllocationPurpose::AuditFunding as u32..=AllocationPurpose::CommunityIncentives as u32
+    {
+        let legacy: Option<LegacyAllocationRecord> =
+            env.storage().persistent().get(&(ALLOCATIONS, purpose));
+        if let Some(legacy) = legacy {
+            let allocation = AllocationRecord {
+                asset: token.clone(),
+                purpose: legacy.purpose,
+                total_allocated: legacy.total_allocated,
+                total_withdrawn: legacy.total_withdrawn,
+                allocation_count: legacy.allocation_count,
+            };
+            env.storage()
+                .persistent()
+                .set(&(ALLOCATIONS, token.clone(), purpose), &allocation);
+            env.storage().persistent().remove(&(ALLOCATIONS, purpose));
+        }
+    }
+
+    Ok(())
+}
+
+/// Load a withdrawal proposal stored in either layout, rewriting a version 1 proposal as a
+/// proposal in `token`. Approved version 1 proposals start their lock-up when rewritten.
+fn load_or_migrate_proposal(
+    env: &Env,
+    proposal_id: u64,
+    token: &Address,
+) -> Option<WithdrawalProposal> {
+    let key = (WITHDRAWAL_PROPOSALS, proposal_id);
+    // Decoding a struct with the wrong layout traps, so tell them apart by their fields first
+    let fields: Map<Symbol, Val> = env.storage().persistent().get(&key)?;
+    if fields.contains_key(Symbol::new(env, "asset")) {
+        return env.storage().persistent().get(&key);
+    }
+
+    let legacy: LegacyWithdrawalProposal = env.storage().persistent().get(&key)?;
+    let proposal = WithdrawalProposal {
+        proposal_id: legacy.proposal_id,
+        recipient: legacy.recipient,
+        asset: token.clone(),
+        amount: legacy.amount,
+        purpose: legacy.purpose,
+        description: legacy.description,
+        proposed_by: legacy.proposed_by,
+        created_at: legacy.created_at,
+        voting_ends_at: legacy.voting_ends_at,
+        governance_proposal_id: 0u64,
+        yes_votes: legacy.yes_votes,
+        no_votes: legacy.no_votes,
+        approved_at: if legacy.status == 1 { env.ledger().timestamp() } else { 0u64 },
+        status: legacy.status,
+        executed: legacy.executed,
+        streamed: false,
+    };
+    env.storage().persistent().set(&key, &proposal);
+    Some(proposal)
+}
+
 fn validate_address(_env: &Env, _address: &Address) -> Result<(), ContractError> {
     Ok(())
 }
@@ -207,20 +460,521 @@ fn set_paused(env: &Env, paused: bool) {
     env.storage().persistent().set(&PAUSED, &paused);
 }
 
-/// Get current treasury balance
-fn get_balance(env: &Env) -> i128 {
-    env.storage().persistent().get(&TREASURY_BALANCE).unwrap_or(0i128)
+/// Get current treasury balance of an asset
+fn get_balance(env: &Env, asset: &Address) -> i128 {
+    env.storage().persistent().get(&(TREASURY_BALANCE, asset)).unwrap_or(0i128)
+}
+
+/// Set treasury balance of an asset with invariant checking
+fn set_balance(env: &Env, asset: &Address, amount: i128) -> Result<(), ContractError> {
+    if amount < 0 {
+        return Err(ContractError::BalanceViolation);
+    }
+    env.storage().persistent().set(&(TREASURY_BALANCE, asset), &amount);
+    Ok(())
+}
+
+/// Assets the treasury accepts, in registration order
+fn get_supported_assets(env: &Env) -> Vec<Address> {
+    env.storage().persistent().get(&SUPPORTED_ASSETS).unwrap_or(Vec::new(env))
+}
+
+fn require_supported_asset(env: &Env, asset: &Address) -> Result<(), ContractError> {
+    if !get_supported_assets(env).contains(asset) {
+        return Err(ContractError::UnsupportedAsset);
+    }
+    Ok(())
+}
+
+fn validate_fee_type(fee_type: u32) -> Result<(), ContractError> {
+    if fee_type < FeeType::PremiumFee as u32 || fee_type > FeeType::Other as u32 {
+        return Err(ContractError::InvalidInput);
+    }
+    Ok(())
+}
+
+fn get_fee_count(env: &Env) -> u64 {
+    env.storage().persistent().get(&FEE_COUNTER).unwrap_or(0u64)
+}
+
+fn load_fee_deposit(env: &Env, fee_id: u64) -> Result<FeeDeposit, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&(FEE_DEPOSITS, fee_id))
+        .ok_or(ContractError::NotFound)
+}
+
+/// Persist a deposit in the fee ledger and add it to the totals of its fee type and source
+fn record_fee_deposit(
+    env: &Env,
+    asset: &Address,
+    from: &Address,
+    source: &Address,
+    fee_type: u32,
+    amount: i128,
+) -> Result<u64, ContractError> {
+    let fee_id = get_fee_count(env) + 1;
+    env.storage().persistent().set(&FEE_COUNTER, &fee_id);
+
+    let deposit = FeeDeposit {
+        fee_id,
+        fee_type,
+        asset: asset.clone(),
+        amount,
+        depositor: from.clone(),
+        timestamp: env.ledger().timestamp(),
+        source_contract: source.clone(),
+    };
+    env.storage().persistent().set(&(FEE_DEPOSITS, fee_id), &deposit);
+
+    let type_key = (FEES_BY_TYPE, asset.clone(), fee_type);
+    let type_total: i128 = env.storage().persistent().get(&type_key).unwrap_or(0i128);
+    let new_type_total = type_total.checked_add(amount).ok_or(ContractError::Overflow)?;
+    env.storage().persistent().set(&type_key, &new_type_total);
+
+    let source_key = (FEES_BY_SOURCE, asset.clone(), source.clone());
+    let source_total: i128 = env.storage().persistent().get(&source_key).unwrap_or(0i128);
+    let new_source_total = source_total.checked_add(amount).ok_or(ContractError::Overflow)?;
+    env.storage().persistent().set(&source_key, &new_source_total);
+
+    Ok(fee_id)
 }
 
-/// Set treasury balance with invariant checking
-fn set_balance(env: &Env, amount: i128) -> Result<(), ContractError> {
+/// First fee_id deposited at or after `timestamp`, or the next id to be assigned if none.
+/// Deposits are recorded in ledger order, so timestamps never decrease as ids grow.
+fn first_fee_at_or_after(env: &Env, timestamp: u64) -> u64 {
+    let mut low = 1u64;
+    let mut high = get_fee_count(env) + 1;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match load_fee_deposit(env, mid) {
+            Ok(deposit) if deposit.timestamp < timestamp => low = mid + 1,
+            _ => high = mid,
+        }
+    }
+    low
+}
+
+/// Transfer `amount` of `asset` from `from` into the treasury, credit it to the ledger balance
+/// and record it in the fee ledger under `source`, the trusted contract that made the deposit.
+/// Returns the new balance and the new total of fees collected in that asset.
+fn collect_fee(
+    env: &Env,
+    asset: &Address,
+    from: &Address,
+    source: &Address,
+    fee_type: u32,
+    amount: i128,
+) -> Result<(i128, i128), ContractError> {
+    require_supported_asset(env, asset)?;
+    validate_fee_type(fee_type)?;
+
+    let new_balance = get_balance(env, asset).checked_add(amount).ok_or(ContractError::Overflow)?;
+    let total_fees: i128 =
+        env.storage().persistent().get(&(TOTAL_FEES_COLLECTED, asset)).unwrap_or(0i128);
+    let new_total = total_fees.checked_add(amount).ok_or(ContractError::Overflow)?;
+
+    token::Client::new(env, asset).transfer(from, &env.current_contract_address(), &amount);
+
+    set_balance(env, asset, new_balance)?;
+    env.storage().persistent().set(&(TOTAL_FEES_COLLECTED, asset), &new_total);
+    record_fee_deposit(env, asset, from, source, fee_type, amount)?;
+
+    Ok((new_balance, new_total))
+}
+
+/// Part of an asset's balance held for streams and not yet claimed
+fn get_reserved(env: &Env, asset: &Address) -> i128 {
+    env.storage().persistent().get(&(RESERVED_BALANCE, asset)).unwrap_or(0i128)
+}
+
+fn set_reserved(env: &Env, asset: &Address, amount: i128) -> Result<(), ContractError> {
     if amount < 0 {
         return Err(ContractError::BalanceViolation);
     }
-    env.storage().persistent().set(&TREASURY_BALANCE, &amount);
+    env.storage().persistent().set(&(RESERVED_BALANCE, asset), &amount);
+    Ok(())
+}
+
+/// Balance of an asset that is not reserved for streams
+fn get_available_balance(env: &Env, asset: &Address) -> i128 {
+    get_balance(env, asset) - get_reserved(env, asset)
+}
+
+/// Debit a payout from the ledger balance and record it against the total withdrawn and the
+/// allocation record of its purpose. Returns the new balance.
+fn record_payout(
+    env: &Env,
+    asset: &Address,
+    purpose: u32,
+    amount: i128,
+) -> Result<i128, ContractError> {
+    let new_balance = get_balance(env, asset)
+        .checked_sub(amount)
+        .ok_or(ContractError::BalanceViolation)?;
+    set_balance(env, asset, new_balance)?;
+
+    // Update total withdrawn
+    let total_withdrawn: i128 =
+        env.storage().persistent().get(&(TOTAL_WITHDRAWN, asset)).unwrap_or(0i128);
+    let new_total_withdrawn = total_withdrawn.checked_add(amount).ok_or(ContractError::Overflow)?;
+    env.storage().persistent().set(&(TOTAL_WITHDRAWN, asset), &new_total_withdrawn);
+
+    // Update allocation record
+    let mut allocation = load_allocation(env, asset, purpose);
+    allocation.total_withdrawn =
+        allocation.total_withdrawn.checked_add(amount).ok_or(ContractError::Overflow)?;
+    env.storage()
+        .persistent()
+        .set(&(ALLOCATIONS, asset.clone(), purpose), &allocation);
+
+    Ok(new_balance)
+}
+
+fn load_allocation(env: &Env, asset: &Address, purpose: u32) -> AllocationRecord {
+    env.storage()
+        .persistent()
+        .get(&(ALLOCATIONS, asset.clone(), purpose))
+        .unwrap_or(AllocationRecord {
+            asset: asset.clone(),
+            purpose,
+            total_allocated: 0i128,
+            total_withdrawn: 0i128,
+            allocation_count: 0u64,
+        })
+}
+
+/// Record an executed withdrawal against the allocation record of its purpose
+fn record_allocation(
+    env: &Env,
+    asset: &Address,
+    purpose: u32,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let mut allocation = load_allocation(env, asset, purpose);
+    allocation.total_allocated =
+        allocation.total_allocated.checked_add(amount).ok_or(ContractError::Overflow)?;
+    allocation.allocation_count += 1;
+    env.storage()
+        .persistent()
+        .set(&(ALLOCATIONS, asset.clone(), purpose), &allocation);
+    Ok(())
+}
+
+/// Ids with `status` in one bucket of the status index, ascending
+fn get_status_bucket(env: &Env, status: u32, bucket: u64) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(PROPOSAL_INDEX, status, bucket))
+        .unwrap_or(Vec::new(env))
+}
+
+fn get_status_count(env: &Env, status: u32) -> u32 {
+    env.storage().persistent().get(&(STATUS_COUNT, status)).unwrap_or(0u32)
+}
+
+/// Insert a proposal into the bucket of `status`, keeping it sorted.
+/// Returns false if it was already indexed under that status.
+fn add_to_status_index(env: &Env, status: u32, proposal_id: u64) -> bool {
+    let bucket = proposal_id / STATUS_BUCKET_SIZE;
+    let mut ids = get_status_bucket(env, status, bucket);
+    let mut position = ids.len();
+    for (i, id) in ids.iter().enumerate() {
+        if id == proposal_id {
+            return false;
+        }
+        if id > proposal_id {
+            position = i as u32;
+            break;
+        }
+    }
+    ids.insert(position, proposal_id);
+    env.storage().persistent().set(&(PROPOSAL_INDEX, status, bucket), &ids);
+    env.storage()
+        .persistent()
+        .set(&(STATUS_COUNT, status), &(get_status_count(env, status) + 1));
+    true
+}
+
+fn remove_from_status_index(env: &Env, status: u32, proposal_id: u64) {
+    let bucket = proposal_id / STATUS_BUCKET_SIZE;
+    let mut ids = get_status_bucket(env, status, bucket);
+    if let Some(position) = ids.first_index_of(proposal_id) {
+        ids.remove(position);
+        if ids.is_empty() {
+            env.storage().persistent().remove(&(PROPOSAL_INDEX, status, bucket));
+        } else {
+            env.storage().persistent().set(&(PROPOSAL_INDEX, status, bucket), &ids);
+        }
+        env.storage()
+            .persistent()
+            .set(&(STATUS_COUNT, status), &get_status_count(env, status).saturating_sub(1));
+    }
+}
+
+fn get_proposal_counter(env: &Env, counter: Symbol, asset: &Address) -> u64 {
+    env.storage().persistent().get(&(counter, asset.clone())).unwrap_or(0u64)
+}
+
+fn bump_proposal_counter(env: &Env, counter: Symbol, asset: &Address) {
+    let count = get_proposal_counter(env, counter.clone(), asset);
+    env.storage().persistent().set(&(counter, asset.clone()), &(count + 1));
+}
+
+/// Add a newly opened proposal to the index of active proposals
+fn index_new_proposal(env: &Env, proposal: &WithdrawalProposal) {
+    add_to_status_index(env, proposal.status, proposal.proposal_id);
+    bump_proposal_counter(env, ACTIVE_PROPOSALS, &proposal.asset);
+}
+
+/// Move a proposal to `status`, keeping the status index and the proposal counters of its
+/// asset in step. The caller persists the proposal.
+fn set_proposal_status(env: &Env, proposal: &mut WithdrawalProposal, status: u32) {
+    remove_from_status_index(env, proposal.status, proposal.proposal_id);
+    add_to_status_index(env, status, proposal.proposal_id);
+
+    // Rejected (2) and executed (3) proposals are no longer active
+    if status >= 2 {
+        let active = get_proposal_counter(env, ACTIVE_PROPOSALS, &proposal.asset);
+        env.storage()
+            .persistent()
+            .set(&(ACTIVE_PROPOSALS, proposal.asset.clone()), &active.saturating_sub(1));
+        bump_proposal_counter(env, COMPLETED_PROPOSALS, &proposal.asset);
+    }
+    if status == 3 {
+        bump_proposal_counter(env, ALLOCATION_COUNT, &proposal.asset);
+    }
+
+    proposal.status = status;
+}
+
+/// Index a proposal created before the status index existed and count it towards its
+/// asset's counters. Proposals that are already indexed are left alone.
+fn backfill_proposal(env: &Env, proposal: &WithdrawalProposal) {
+    if !add_to_status_index(env, proposal.status, proposal.proposal_id) {
+        return;
+    }
+    if proposal.status < 2 {
+        bump_proposal_counter(env, ACTIVE_PROPOSALS, &proposal.asset);
+    } else {
+        bump_proposal_counter(env, COMPLETED_PROPOSALS, &proposal.asset);
+    }
+    if proposal.status == 3 {
+        bump_proposal_counter(env, ALLOCATION_COUNT, &proposal.asset);
+    }
+}
+
+fn validate_purpose(purpose: u32) -> Result<(), ContractError> {
+    if purpose < AllocationPurpose::AuditFunding as u32
+        || purpose > AllocationPurpose::CommunityIncentives as u32
+    {
+        return Err(ContractError::InvalidInput);
+    }
+    Ok(())
+}
+
+fn current_epoch(env: &Env) -> u64 {
+    env.ledger().timestamp() / TREASURY_BUDGET_EPOCH_SECONDS
+}
+
+/// Budget per epoch for a purpose in an asset (0 = no budget)
+fn get_budget(env: &Env, asset: &Address, purpose: u32) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(PURPOSE_BUDGETS, asset, purpose))
+        .unwrap_or(0i128)
+}
+
+/// Amount executed for a purpose in an asset during the current epoch
+fn get_budget_spent(env: &Env, asset: &Address, purpose: u32) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&(BUDGET_SPENT, asset, purpose, current_epoch(env)))
+        .unwrap_or(0i128)
+}
+
+fn budget_utilization(env: &Env, asset: &Address, purpose: u32) -> BudgetUtilization {
+    let budget = get_budget(env, asset, purpose);
+    let spent = get_budget_spent(env, asset, purpose);
+    BudgetUtilization {
+        asset: asset.clone(),
+        purpose,
+        epoch: current_epoch(env),
+        budget,
+        spent,
+        remaining: if budget > spent { budget - spent } else { 0 },
+    }
+}
+
+/// Enforce the minimum allocation, the per-transaction cap relative to the available balance
+/// and the purpose's remaining budget for the current epoch
+fn check_spending_limits(
+    env: &Env,
+    asset: &Address,
+    purpose: u32,
+    amount: i128,
+) -> Result<(), ContractError> {
+    if amount < MIN_ALLOCATION_AMOUNT {
+        return Err(ContractError::AllocationTooSmall);
+    }
+
+    let available = get_available_balance(env, asset);
+    if amount > available {
+        return Err(ContractError::InsufficientFunds);
+    }
+
+    let cap = available
+        .checked_mul(MAX_ALLOCATION_PERCENT_PER_TX as i128)
+        .ok_or(ContractError::Overflow)?
+        / 100;
+    if amount > cap {
+        return Err(ContractError::AllocationCapExceeded);
+    }
+
+    let budget = get_budget(env, asset, purpose);
+    if budget > 0 {
+        let spent = get_budget_spent(env, asset, purpose)
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+        if spent > budget {
+            return Err(ContractError::BudgetExceeded);
+        }
+    }
+
+    Ok(())
+}
+
+/// Charge an executed withdrawal to its purpose's spending in the current epoch
+fn record_budget_spend(
+    env: &Env,
+    asset: &Address,
+    purpose: u32,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let spent = get_budget_spent(env, asset, purpose)
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    env.storage()
+        .persistent()
+        .set(&(BUDGET_SPENT, asset, purpose, current_epoch(env)), &spent);
+    Ok(())
+}
+
+/// Give back budget that was charged but will never be paid out. Spending from an earlier
+/// epoch is not carried over, so the current epoch's spending is not taken below zero.
+fn release_budget_spend(env: &Env, asset: &Address, purpose: u32, amount: i128) {
+    let spent = get_budget_spent(env, asset, purpose);
+    let remaining = if spent > amount { spent - amount } else { 0 };
+    env.storage()
+        .persistent()
+        .set(&(BUDGET_SPENT, asset, purpose, current_epoch(env)), &remaining);
+}
+
+fn validate_schedule(env: &Env, schedule: &VestingSchedule) -> Result<(), ContractError> {
+    if schedule.start >= schedule.end
+        || schedule.cliff < schedule.start
+        || schedule.cliff > schedule.end
+        || schedule.end <= env.ledger().timestamp()
+    {
+        return Err(ContractError::InvalidInput);
+    }
     Ok(())
 }
 
+/// Amount of a stream vested at `now`: nothing before the cliff, then linear until the end
+fn vested_amount(stream: &Stream, now: u64) -> i128 {
+    let schedule = &stream.schedule;
+    if stream.cancelled || now >= schedule.end {
+        return stream.total;
+    }
+    if now < schedule.cliff {
+        return 0;
+    }
+    let elapsed = (now - schedule.start) as i128;
+    let duration = (schedule.end - schedule.start) as i128;
+    stream.total * elapsed / duration
+}
+
+fn load_stream(env: &Env, stream_id: u64) -> Result<Stream, ContractError> {
+    env.storage()
+        .persistent()
+        .get(&(STREAMS, stream_id))
+        .ok_or(ContractError::NotFound)
+}
+
+/// Store a new withdrawal proposal awaiting governance approval
+fn open_withdrawal(
+    env: &Env,
+    proposer: &Address,
+    recipient: Address,
+    asset: Address,
+    amount: i128,
+    purpose: u32,
+    description: Symbol,
+    schedule: Option<VestingSchedule>,
+) -> Result<u64, ContractError> {
+    if is_paused(env) {
+        return Err(ContractError::Paused);
+    }
+
+    proposer.require_auth();
+    validate_amount(amount)?;
+    validate_address(env, &recipient)?;
+    validate_purpose(purpose)?;
+    require_supported_asset(env, &asset)?;
+    check_spending_limits(env, &asset, purpose, amount)?;
+
+    if !env.storage().persistent().has(&CONFIG) {
+        return Err(ContractError::NotInitialized);
+    }
+
+    // Get current timestamp for voting period (7 days = 604800 seconds)
+    let now = env.ledger().timestamp();
+    let voting_period = 7u64 * 24 * 60 * 60; // 7 days
+    let voting_ends_at = now + voting_period;
+
+    let proposal_id = next_proposal_id(env);
+
+    let proposal = WithdrawalProposal {
+        proposal_id,
+        recipient,
+        asset,
+        amount,
+        purpose,
+        description,
+        proposed_by: proposer.clone(),
+        created_at: now,
+        voting_ends_at,
+        governance_proposal_id: 0u64,
+        yes_votes: 0i128,
+        no_votes: 0i128,
+        approved_at: 0u64,
+        status: 0, // Active
+        executed: false,
+        streamed: schedule.is_some(),
+    };
+
+    env.storage().persistent().set(&(WITHDRAWAL_PROPOSALS, proposal_id), &proposal);
+    index_new_proposal(env, &proposal);
+    if let Some(schedule) = schedule {
+        env.storage().persistent().set(&(STREAM_SCHEDULES, proposal_id), &schedule);
+    }
+
+    env.events().publish(
+        (Symbol::new(env, "withdrawal_proposed"), proposal.recipient.clone()),
+        (
+            proposal_id,
+            proposal.asset,
+            proposal.amount,
+            proposal.purpose,
+            proposal.proposed_by,
+            proposal.voting_ends_at,
+        ),
+    );
+
+    Ok(proposal_id)
+}
+
 /// Get next proposal ID
 fn next_proposal_id(env: &Env) -> u64 {
     let current_id: u64 = env.storage().persistent().get(&PROPOSAL_COUNTER).unwrap_or(0u64);
@@ -242,6 +996,15 @@ fn is_trusted_contract(env: &Env, contract: &Address) -> bool {
     env.storage().persistent().has(&(TRUSTED_CONTRACTS, contract))
 }
 
+/// Require the contract depositing a fee to authorize the call and be registered as trusted
+fn require_trusted_source(env: &Env, source: &Address) -> Result<(), ContractError> {
+    source.require_auth();
+    if !is_trusted_contract(env, source) {
+        return Err(ContractError::NotTrustedContract);
+    }
+    Ok(())
+}
+
 // ============================================================================
 // Contract Implementation
 // ============================================================================
@@ -254,15 +1017,17 @@ impl TreasuryContract {
         env: Env,
         admin: Address,
         governance_contract: Address,
+        token: Address,
         fee_percentage: u32,
     ) -> Result<(), ContractError> {
         // Check if already initialized
-        if insurance_contracts::authorization::get_admin(&env).is_some() {
+        if insurance_contracts::authorization::is_admin_initialized(&env) {
             return Err(ContractError::AlreadyInitialized);
         }
 
         validate_address(&env, &admin)?;
         validate_address(&env, &governance_contract)?;
+        validate_address(&env, &token)?;
 
         if fee_percentage == 0 || fee_percentage > 10000 {
             return Err(ContractError::InvalidInput);
@@ -270,13 +1035,20 @@ impl TreasuryContract {
 
         admin.require_auth();
         initialize_admin(&env, admin.clone());
+        VersionManager::initialize(&env, STORAGE_VERSION).map_err(map_versioning_error)?;
 
-        let config = TreasuryConfig { admin: admin.clone(), governance_contract, fee_percentage };
+        let mut assets = Vec::new(&env);
+        assets.push_back(token.clone());
+
+        let config = TreasuryConfig {
+            admin: admin.clone(),
+            governance_contract,
+            token,
+            fee_percentage,
+        };
 
         env.storage().persistent().set(&CONFIG, &config);
-        env.storage().persistent().set(&TREASURY_BALANCE, &0i128);
-        env.storage().persistent().set(&TOTAL_FEES_COLLECTED, &0i128);
-        env.storage().persistent().set(&TOTAL_WITHDRAWN, &0i128);
+        env.storage().persistent().set(&SUPPORTED_ASSETS, &assets);
         env.storage().persistent().set(&PROPOSAL_COUNTER, &0u64);
 
         env.events().publish((Symbol::new(&env, "treasury_initialized"), ()), admin);
@@ -287,12 +1059,11 @@ impl TreasuryContract {
     /// Register a trusted contract that can deposit fees
     pub fn register_trusted_contract(
         env: Env,
+        admin: Address,
         contract_address: Address,
     ) -> Result<(), ContractError> {
-        // Admin-only: use transaction source as the acting address.
-        let caller = env.current_contract_address();
-        require_admin(&env, &caller)?;
-        require_admin(&env)?;
+        admin.require_auth();
+        require_admin(&env, &admin)?;
         validate_address(&env, &contract_address)?;
 
         env.storage().persistent().set(&(TRUSTED_CONTRACTS, &contract_address), &true);
@@ -305,42 +1076,65 @@ impl TreasuryContract {
         Ok(())
     }
 
-    /// Deposit premium fees from policy contract
-    pub fn deposit_premium_fee(env: Env, from: Address, amount: i128) -> Result<(), ContractError> {
-        if is_paused(&env) {
-            return Err(ContractError::Paused);
+    /// Accept fees and withdrawals in an additional asset (admin only)
+    pub fn add_supported_asset(
+        env: Env,
+        admin: Address,
+        asset: Address,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+        validate_address(&env, &asset)?;
+
+        let mut assets = get_supported_assets(&env);
+        if assets.contains(&asset) {
+            return Err(ContractError::AlreadyExists);
         }
+        assets.push_back(asset.clone());
+        env.storage().persistent().set(&SUPPORTED_ASSETS, &assets);
 
-        validate_amount(amount)?;
+        env.events()
+            .publish((Symbol::new(&env, "asset_supported"), asset.clone()), asset);
 
-        // Verify caller is a trusted contract
-        let caller = invoker(&env);
-        if !is_trusted_contract(&env, &caller) {
-            return Err(ContractError::NotTrustedContract);
-        }
+        Ok(())
+    }
+
+    /// Set how much of `asset` may be withdrawn for `purpose` per budget epoch (admin only).
+    /// A budget of 0 removes the limit, leaving only the per-transaction cap.
+    pub fn set_purpose_budget(
+        env: Env,
+        admin: Address,
+        asset: Address,
+        purpose: u32,
+        budget: i128,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+        validate_purpose(purpose)?;
+        require_supported_asset(&env, &asset)?;
 
-        let current_balance = get_balance(&env);
-        let new_balance = current_balance.checked_add(amount).ok_or(ContractError::Overflow)?;
-        set_balance(&env, new_balance)?;
+        if budget < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
 
-        // Update total fees collected
-        let total_fees: i128 =
-            env.storage().persistent().get(&TOTAL_FEES_COLLECTED).unwrap_or(0i128);
-        let new_total = total_fees.checked_add(amount).ok_or(ContractError::Overflow)?;
-        env.storage().persistent().set(&TOTAL_FEES_COLLECTED, &new_total);
+        env.storage().persistent().set(&(PURPOSE_BUDGETS, &asset, purpose), &budget);
 
         env.events().publish(
-            (Symbol::new(&env, "premium_fee_deposited"), from.clone()),
-            (amount, new_balance, new_total),
+            (Symbol::new(&env, "purpose_budget_set"), asset.clone()),
+            (asset, purpose, budget),
         );
 
         Ok(())
     }
 
-    /// Deposit claim penalty fees
-    pub fn deposit_claim_penalty(
+    /// Deposit premium fees from policy contract
+    /// Transfers `amount` of `asset` from `from`.
+    /// `source` is the trusted contract making the deposit.
+    pub fn deposit_premium_fee(
         env: Env,
+        source: Address,
         from: Address,
+        asset: Address,
         amount: i128,
     ) -> Result<(), ContractError> {
         if is_paused(&env) {
@@ -349,34 +1143,26 @@ impl TreasuryContract {
 
         validate_amount(amount)?;
 
-        // Verify caller is a trusted contract
-        let caller = invoker(&env);
-        if !is_trusted_contract(&env, &caller) {
-            return Err(ContractError::NotTrustedContract);
-        }
-
-        let current_balance = get_balance(&env);
-        let new_balance = current_balance.checked_add(amount).ok_or(ContractError::Overflow)?;
-        set_balance(&env, new_balance)?;
+        // Verify the depositing contract is trusted
+        require_trusted_source(&env, &source)?;
 
-        // Update total fees collected
-        let total_fees: i128 =
-            env.storage().persistent().get(&TOTAL_FEES_COLLECTED).unwrap_or(0i128);
-        let new_total = total_fees.checked_add(amount).ok_or(ContractError::Overflow)?;
-        env.storage().persistent().set(&TOTAL_FEES_COLLECTED, &new_total);
+        let (new_balance, new_total) =
+            collect_fee(&env, &asset, &from, &source, FeeType::PremiumFee as u32, amount)?;
 
         env.events().publish(
-            (Symbol::new(&env, "claim_penalty_deposited"), from.clone()),
-            (amount, new_balance, new_total),
+            (Symbol::new(&env, "premium_fee_deposited"), from.clone()),
+            (asset, amount, new_balance, new_total),
         );
 
         Ok(())
     }
 
-    /// Deposit slashing fees
-    pub fn deposit_slashing_fee(
+    /// Deposit claim penalty fees
+    pub fn deposit_claim_penalty(
         env: Env,
+        source: Address,
         from: Address,
+        asset: Address,
         amount: i128,
     ) -> Result<(), ContractError> {
         if is_paused(&env) {
@@ -385,25 +1171,43 @@ impl TreasuryContract {
 
         validate_amount(amount)?;
 
-        // Verify caller is a trusted contract
-        let caller = invoker(&env);
-        if !is_trusted_contract(&env, &caller) {
-            return Err(ContractError::NotTrustedContract);
+        // Verify the depositing contract is trusted
+        require_trusted_source(&env, &source)?;
+
+        let (new_balance, new_total) =
+            collect_fee(&env, &asset, &from, &source, FeeType::ClaimPenalty as u32, amount)?;
+
+        env.events().publish(
+            (Symbol::new(&env, "claim_penalty_deposited"), from.clone()),
+            (asset, amount, new_balance, new_total),
+        );
+
+        Ok(())
+    }
+
+    /// Deposit slashing fees
+    pub fn deposit_slashing_fee(
+        env: Env,
+        source: Address,
+        from: Address,
+        asset: Address,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
         }
 
-        let current_balance = get_balance(&env);
-        let new_balance = current_balance.checked_add(amount).ok_or(ContractError::Overflow)?;
-        set_balance(&env, new_balance)?;
+        validate_amount(amount)?;
 
-        // Update total fees collected
-        let total_fees: i128 =
-            env.storage().persistent().get(&TOTAL_FEES_COLLECTED).unwrap_or(0i128);
-        let new_total = total_fees.checked_add(amount).ok_or(ContractError::Overflow)?;
-        env.storage().persistent().set(&TOTAL_FEES_COLLECTED, &new_total);
+        // Verify the depositing contract is trusted
+        require_trusted_source(&env, &source)?;
+
+        let (new_balance, new_total) =
+            collect_fee(&env, &asset, &from, &source, FeeType::SlashingFee as u32, amount)?;
 
         env.events().publish(
             (Symbol::new(&env, "slashing_fee_deposited"), from.clone()),
-            (amount, new_balance, new_total),
+            (asset, amount, new_balance, new_total),
         );
 
         Ok(())
@@ -412,7 +1216,9 @@ impl TreasuryContract {
     /// Generic fee deposit function for other sources
     pub fn deposit_fee(
         env: Env,
+        source: Address,
         from: Address,
+        asset: Address,
         amount: i128,
         fee_type: u32,
     ) -> Result<(), ContractError> {
@@ -422,25 +1228,14 @@ impl TreasuryContract {
 
         validate_amount(amount)?;
 
-        // Verify caller is a trusted contract
-        let caller = invoker(&env);
-        if !is_trusted_contract(&env, &caller) {
-            return Err(ContractError::NotTrustedContract);
-        }
-
-        let current_balance = get_balance(&env);
-        let new_balance = current_balance.checked_add(amount).ok_or(ContractError::Overflow)?;
-        set_balance(&env, new_balance)?;
+        // Verify the depositing contract is trusted
+        require_trusted_source(&env, &source)?;
 
-        // Update total fees collected
-        let total_fees: i128 =
-            env.storage().persistent().get(&TOTAL_FEES_COLLECTED).unwrap_or(0i128);
-        let new_total = total_fees.checked_add(amount).ok_or(ContractError::Overflow)?;
-        env.storage().persistent().set(&TOTAL_FEES_COLLECTED, &new_total);
+        let (new_balance, new_total) = collect_fee(&env, &asset, &from, &source, fee_type, amount)?;
 
         env.events().publish(
             (Symbol::new(&env, "fee_deposited"), from.clone()),
-            (amount, fee_type, new_balance, new_total),
+            (asset, amount, fee_type, new_balance, new_total),
         );
 
         Ok(())
@@ -451,74 +1246,53 @@ impl TreasuryContract {
         env: Env,
         proposer: Address,
         recipient: Address,
+        asset: Address,
         amount: i128,
         purpose: u32,
         description: Symbol,
     ) -> Result<u64, ContractError> {
-        if is_paused(&env) {
-            return Err(ContractError::Paused);
-        }
-
-        proposer.require_auth();
-        validate_amount(amount)?;
-        validate_address(&env, &recipient)?;
-
-        // Check treasury has sufficient balance
-        let balance = get_balance(&env);
-        if amount > balance {
-            return Err(ContractError::InsufficientFunds);
-        }
-
-        // Get config
-        let config: TreasuryConfig =
-            env.storage().persistent().get(&CONFIG).ok_or(ContractError::NotInitialized)?;
-
-        // Get current timestamp for voting period (7 days = 604800 seconds)
-        let now = env.ledger().timestamp();
-        let voting_period = 7u64 * 24 * 60 * 60; // 7 days
-        let voting_ends_at = now + voting_period;
-
-        let proposal_id = next_proposal_id(&env);
+        open_withdrawal(&env, &proposer, recipient, asset, amount, purpose, description, None)
+    }
 
-        let proposal = WithdrawalProposal {
-            proposal_id,
-            recipient: recipient.clone(),
+    /// Create a withdrawal proposal paid out as a stream vesting over `schedule`
+    /// (DAO governance required). The recipient claims vested funds with `claim_vested`.
+    pub fn propose_stream(
+        env: Env,
+        proposer: Address,
+        recipient: Address,
+        asset: Address,
+        amount: i128,
+        purpose: u32,
+        description: Symbol,
+        schedule: VestingSchedule,
+    ) -> Result<u64, ContractError> {
+        validate_schedule(&env, &schedule)?;
+        open_withdrawal(
+            &env,
+            &proposer,
+            recipient,
+            asset,
             amount,
             purpose,
-            description: description.clone(),
-            proposed_by: proposer.clone(),
-            created_at: now,
-            voting_ends_at,
-            yes_votes: 0i128,
-            no_votes: 0i128,
-            status: 0, // Active
-            executed: false,
-        };
-
-        env.storage().persistent().set(&(WITHDRAWAL_PROPOSALS, proposal_id), &proposal);
-
-        env.events().publish(
-            (Symbol::new(&env, "withdrawal_proposed"), proposal.recipient.clone()),
-            (
-                proposal_id,
-                proposal.amount,
-                proposal.purpose,
-                proposal.proposed_by,
-                proposal.voting_ends_at,
-            ),
-        );
-
-        Ok(proposal_id)
+            description,
+            Some(schedule),
+        )
     }
 
-    /// Execute approved withdrawal (DAO governance required)
-    pub fn execute_withdrawal(env: Env, proposal_id: u64) -> Result<(), ContractError> {
+    /// Execute approved withdrawal and pay out to the recipient (DAO governance required).
+    /// Streamed withdrawals reserve the amount and start their stream instead.
+    /// Only possible once the lock-up after approval has passed, and within the spending limits.
+    pub fn execute_withdrawal(
+        env: Env,
+        admin: Address,
+        proposal_id: u64,
+    ) -> Result<(), ContractError> {
         if is_paused(&env) {
             return Err(ContractError::Paused);
         }
 
-        let caller = env.current_contract_address();
-        require_admin(&env, &caller)?;
+        admin.require_auth();
+        require_admin(&env, &admin)?;
 
         // Get proposal
         let mut proposal: WithdrawalProposal = env
@@ -537,58 +1311,165 @@ impl TreasuryContract {
             return Err(ContractError::ProposalNotApproved);
         }
 
-        // Check treasury has sufficient balance
-        let balance = get_balance(&env);
-        if proposal.amount > balance {
-            return Err(ContractError::InsufficientFunds);
+        // Give the community time to react between approval and payout
+        if env.ledger().timestamp() < proposal.approved_at + TREASURY_LOCKUP_SECONDS {
+            return Err(ContractError::LockupPeriodActive);
         }
 
-        // Execute withdrawal
-        let new_balance =
-            balance.checked_sub(proposal.amount).ok_or(ContractError::BalanceViolation)?;
-        set_balance(&env, new_balance)?;
-
-        // Update total withdrawn
-        let total_withdrawn: i128 =
-            env.storage().persistent().get(&TOTAL_WITHDRAWN).unwrap_or(0i128);
-        let new_total_withdrawn =
-            total_withdrawn.checked_add(proposal.amount).ok_or(ContractError::Overflow)?;
-        env.storage().persistent().set(&TOTAL_WITHDRAWN, &new_total_withdrawn);
-
-        // Update allocation record
-        let mut allocation: AllocationRecord =
-            env.storage().persistent().get(&(ALLOCATIONS, proposal.purpose)).unwrap_or(
-                AllocationRecord {
-                    purpose: proposal.purpose,
-                    total_allocated: 0i128,
-                    total_withdrawn: 0i128,
-                    allocation_count: 0u64,
-                },
-            );
-
-        allocation.total_withdrawn = allocation
-            .total_withdrawn
-            .checked_add(proposal.amount)
-            .ok_or(ContractError::Overflow)?;
-        env.storage().persistent().set(&(ALLOCATIONS, proposal.purpose), &allocation);
+        // Limits are checked again against the balance and budget at execution time
+        check_spending_limits(&env, &proposal.asset, proposal.purpose, proposal.amount)?;
 
         // Mark proposal as executed
         proposal.executed = true;
+        set_proposal_status(&env, &mut proposal, 3); // Executed
         env.storage().persistent().set(&(WITHDRAWAL_PROPOSALS, proposal_id), &proposal);
+        record_budget_spend(&env, &proposal.asset, proposal.purpose, proposal.amount)?;
+        record_allocation(&env, &proposal.asset, proposal.purpose, proposal.amount)?;
+
+        if proposal.streamed {
+            let schedule: VestingSchedule = env
+                .storage()
+                .persistent()
+                .get(&(STREAM_SCHEDULES, proposal_id))
+                .ok_or(ContractError::NotFound)?;
+
+            let reserved = get_reserved(&env, &proposal.asset)
+                .checked_add(proposal.amount)
+                .ok_or(ContractError::Overflow)?;
+            set_reserved(&env, &proposal.asset, reserved)?;
+
+            let stream = Stream {
+                stream_id: proposal_id,
+                recipient: proposal.recipient.clone(),
+                asset: proposal.asset.clone(),
+                purpose: proposal.purpose,
+                total: proposal.amount,
+                claimed: 0i128,
+                schedule,
+                cancelled: false,
+            };
+            env.storage().persistent().set(&(STREAMS, proposal_id), &stream);
+
+            env.events().publish(
+                (Symbol::new(&env, "stream_started"), proposal.recipient.clone()),
+                (proposal_id, proposal.asset, proposal.amount, stream.schedule.end),
+            );
+
+            return Ok(());
+        }
+
+        // Execute withdrawal
+        let new_balance = record_payout(&env, &proposal.asset, proposal.purpose, proposal.amount)?;
+
+        // Pay out only after all accounting is persisted
+        token::Client::new(&env, &proposal.asset).transfer(
+            &env.current_contract_address(),
+            &proposal.recipient,
+            &proposal.amount,
+        );
 
         env.events().publish(
             (Symbol::new(&env, "withdrawal_executed"), proposal.recipient.clone()),
-            (proposal_id, proposal.amount, new_balance, proposal.purpose),
+            (proposal_id, proposal.asset, proposal.amount, new_balance, proposal.purpose),
+        );
+
+        Ok(())
+    }
+
+    /// Transfer everything vested and not yet claimed from a stream to its recipient.
+    /// Returns the amount paid out.
+    pub fn claim_vested(env: Env, stream_id: u64) -> Result<i128, ContractError> {
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        let mut stream = load_stream(&env, stream_id)?;
+        stream.recipient.require_auth();
+
+        let claimable = vested_amount(&stream, env.ledger().timestamp()) - stream.claimed;
+        if claimable <= 0 {
+            return Err(ContractError::NothingToClaim);
+        }
+
+        stream.claimed += claimable;
+        env.storage().persistent().set(&(STREAMS, stream_id), &stream);
+
+        let reserved = get_reserved(&env, &stream.asset) - claimable;
+        set_reserved(&env, &stream.asset, reserved)?;
+        let new_balance = record_payout(&env, &stream.asset, stream.purpose, claimable)?;
+
+        token::Client::new(&env, &stream.asset).transfer(
+            &env.current_contract_address(),
+            &stream.recipient,
+            &claimable,
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "vested_claimed"), stream.recipient.clone()),
+            (stream_id, stream.asset, claimable, new_balance),
+        );
+
+        Ok(claimable)
+    }
+
+    /// Stop a stream. Funds vested so far stay claimable by the recipient; the unvested
+    /// remainder is released back to the free balance.
+    /// Only callable by the governance contract.
+    pub fn cancel_stream(env: Env, stream_id: u64) -> Result<(), ContractError> {
+        let config: TreasuryConfig =
+            env.storage().persistent().get(&CONFIG).ok_or(ContractError::NotInitialized)?;
+        config.governance_contract.require_auth();
+
+        let mut stream = load_stream(&env, stream_id)?;
+        if stream.cancelled {
+            return Err(ContractError::InvalidState);
+        }
+
+        let vested = vested_amount(&stream, env.ledger().timestamp());
+        let unvested = stream.total - vested;
+
+        stream.total = vested;
+        stream.cancelled = true;
+        env.storage().persistent().set(&(STREAMS, stream_id), &stream);
+
+        let reserved = get_reserved(&env, &stream.asset) - unvested;
+        set_reserved(&env, &stream.asset, reserved)?;
+
+        // The unvested part is no longer allocated to the stream's purpose
+        let mut allocation = load_allocation(&env, &stream.asset, stream.purpose);
+        allocation.total_allocated -= unvested;
+        env.storage()
+            .persistent()
+            .set(&(ALLOCATIONS, stream.asset.clone(), stream.purpose), &allocation);
+        release_budget_spend(&env, &stream.asset, stream.purpose, unvested);
+
+        env.events().publish(
+            (Symbol::new(&env, "stream_cancelled"), stream.recipient.clone()),
+            (stream_id, stream.asset, vested, unvested),
         );
 
         Ok(())
     }
 
+    /// Get a stream created by a streamed withdrawal
+    pub fn get_stream(env: Env, stream_id: u64) -> Result<Stream, ContractError> {
+        load_stream(&env, stream_id)
+    }
+
+    /// Amount of a stream that can be claimed right now
+    pub fn get_claimable(env: Env, stream_id: u64) -> Result<i128, ContractError> {
+        let stream = load_stream(&env, stream_id)?;
+        Ok(vested_amount(&stream, env.ledger().timestamp()) - stream.claimed)
+    }
+
     /// Reject a withdrawal proposal
-    pub fn reject_proposal(env: Env, proposal_id: u64) -> Result<(), ContractError> {
-        let caller = env.current_contract_address();
-        require_admin(&env, &caller)?;
-        require_admin(&env)?;
+    pub fn reject_proposal(
+        env: Env,
+        admin: Address,
+        proposal_id: u64,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
 
         let mut proposal: WithdrawalProposal = env
             .storage()
@@ -596,11 +1477,11 @@ impl TreasuryContract {
             .get(&(WITHDRAWAL_PROPOSALS, proposal_id))
             .ok_or(ContractError::NotFound)?;
 
-        if proposal.executed {
+        if proposal.executed || proposal.status == 2 {
             return Err(ContractError::InvalidState);
         }
 
-        proposal.status = 2; // Rejected
+        set_proposal_status(&env, &mut proposal, 2); // Rejected
         env.storage().persistent().set(&(WITHDRAWAL_PROPOSALS, proposal_id), &proposal);
 
         env.events().publish(
@@ -611,11 +1492,22 @@ impl TreasuryContract {
         Ok(())
     }
 
-    /// Approve a withdrawal proposal (DAO governance required)
-    pub fn approve_proposal(env: Env, proposal_id: u64) -> Result<(), ContractError> {
-        let caller = env.current_contract_address();
-        require_admin(&env, &caller)?;
-        require_admin(&env)?;
+    /// Approve a withdrawal proposal.
+    /// Only callable by the governance contract when executing a passed `TreasuryAllocation`
+    /// proposal, which passes on the withdrawal terms it voted on and its tally.
+    pub fn approve_proposal(
+        env: Env,
+        proposal_id: u64,
+        governance_proposal_id: u64,
+        recipient: Address,
+        asset: Address,
+        amount: i128,
+        yes_votes: i128,
+        no_votes: i128,
+    ) -> Result<(), ContractError> {
+        let config: TreasuryConfig =
+            env.storage().persistent().get(&CONFIG).ok_or(ContractError::NotInitialized)?;
+        config.governance_contract.require_auth();
 
         let mut proposal: WithdrawalProposal = env
             .storage()
@@ -627,45 +1519,101 @@ impl TreasuryContract {
             return Err(ContractError::InvalidState);
         }
 
-        // Check voting period has ended
-        let now = env.ledger().timestamp();
-        if now < proposal.voting_ends_at {
-            return Err(ContractError::VotingPeriodEnded);
+        if proposal.status != 0 {
+            return Err(ContractError::ProposalNotActive);
+        }
+
+        // The vote only covers the terms governance recorded when the proposal was created
+        if proposal.recipient != recipient || proposal.asset != asset || proposal.amount != amount {
+            return Err(ContractError::InvalidInput);
+        }
+
+        if governance_proposal_id == 0 || yes_votes <= no_votes {
+            return Err(ContractError::ThresholdNotMet);
         }
 
-        proposal.status = 1; // Approved
+        set_proposal_status(&env, &mut proposal, 1); // Approved
+        proposal.governance_proposal_id = governance_proposal_id;
+        proposal.yes_votes = yes_votes;
+        proposal.no_votes = no_votes;
+        proposal.approved_at = env.ledger().timestamp();
         env.storage().persistent().set(&(WITHDRAWAL_PROPOSALS, proposal_id), &proposal);
 
         env.events().publish(
             (Symbol::new(&env, "proposal_approved"), proposal.recipient.clone()),
-            (proposal_id, proposal.amount, proposal.purpose),
+            (proposal_id, proposal.amount, proposal.purpose, governance_proposal_id),
         );
 
         Ok(())
     }
 
-    /// Get current treasury balance
-    pub fn get_balance(env: Env) -> i128 {
-        get_balance(&env)
+    /// Get current treasury balance of an asset
+    pub fn get_balance(env: Env, asset: Address) -> i128 {
+        get_balance(&env, &asset)
+    }
+
+    /// Get the treasury balance of every supported asset
+    pub fn get_balances(env: Env) -> Map<Address, i128> {
+        let mut balances = Map::new(&env);
+        for asset in get_supported_assets(&env).iter() {
+            let balance = get_balance(&env, &asset);
+            balances.set(asset, balance);
+        }
+        balances
+    }
+
+    /// Balance of an asset not reserved for streams, available for new withdrawals
+    pub fn get_available_balance(env: Env, asset: Address) -> i128 {
+        get_available_balance(&env, &asset)
     }
 
-    /// Get treasury statistics
-    pub fn get_stats(env: Env) -> Result<TreasuryStats, ContractError> {
+    /// Balance of an asset reserved for streams and not yet claimed
+    pub fn get_reserved_balance(env: Env, asset: Address) -> i128 {
+        get_reserved(&env, &asset)
+    }
+
+    /// Assets the treasury accepts fees and pays withdrawals in
+    pub fn get_supported_assets(env: Env) -> Vec<Address> {
+        get_supported_assets(&env)
+    }
+
+    /// Compare the ledger balance with the token balance actually held by the contract.
+    /// Direct transfers that bypass the `deposit_*` functions show up as a positive difference.
+    pub fn reconcile_balance(
+        env: Env,
+        asset: Address,
+    ) -> Result<BalanceReconciliation, ContractError> {
+        require_supported_asset(&env, &asset)?;
+
+        let ledger_balance = get_balance(&env, &asset);
+        let token_balance =
+            token::Client::new(&env, &asset).balance(&env.current_contract_address());
+
+        Ok(BalanceReconciliation {
+            asset,
+            ledger_balance,
+            token_balance,
+            difference: token_balance - ledger_balance,
+        })
+    }
+
+    /// Get treasury statistics for an asset
+    pub fn get_stats(env: Env, asset: Address) -> Result<TreasuryStats, ContractError> {
         let total_fees: i128 =
-            env.storage().persistent().get(&TOTAL_FEES_COLLECTED).unwrap_or(0i128);
+            env.storage().persistent().get(&(TOTAL_FEES_COLLECTED, &asset)).unwrap_or(0i128);
 
-        let total_balance = get_balance(&env);
+        let total_balance = get_balance(&env, &asset);
 
         let total_withdrawn: i128 =
-            env.storage().persistent().get(&TOTAL_WITHDRAWN).unwrap_or(0i128);
+            env.storage().persistent().get(&(TOTAL_WITHDRAWN, &asset)).unwrap_or(0i128);
 
         Ok(TreasuryStats {
             total_fees_collected: total_fees,
             total_balance,
             total_withdrawn,
-            active_proposals: 0u64,
-            completed_proposals: 0u64,
-            total_allocations: 0u64,
+            active_proposals: get_proposal_counter(&env, ACTIVE_PROPOSALS, &asset),
+            completed_proposals: get_proposal_counter(&env, COMPLETED_PROPOSALS, &asset),
+            total_allocations: get_proposal_counter(&env, ALLOCATION_COUNT, &asset),
         })
     }
 
@@ -677,18 +1625,219 @@ impl TreasuryContract {
             .ok_or(ContractError::NotFound)
     }
 
-    /// Get allocation record for a purpose
-    pub fn get_allocation(env: Env, purpose: u32) -> Result<AllocationRecord, ContractError> {
+    /// Get the recipient, asset, amount and status of a withdrawal proposal,
+    /// as recorded by governance when it opens a vote on the withdrawal
+    pub fn get_withdrawal_terms(
+        env: Env,
+        proposal_id: u64,
+    ) -> Result<(Address, Address, i128, u32), ContractError> {
+        let proposal = Self::get_proposal(env, proposal_id)?;
+        Ok((proposal.recipient, proposal.asset, proposal.amount, proposal.status))
+    }
+
+    /// Returns a paginated list of withdrawal proposals with the given status, oldest first.
+    ///
+    /// # Arguments
+    /// * `status` - 0=Active, 1=Approved, 2=Rejected, 3=Executed
+    /// * `cursor` - Proposal id to start from; 0 for the first page, then the returned `next_cursor`
+    /// * `limit` - Maximum number of proposals to return (capped at 50; 0 returns a full page)
+    ///
+    /// # Returns
+    /// * `WithdrawalProposalPage` containing the proposals, the total matching count and the
+    ///   cursor of the next page. A page may come back short with a non-zero `next_cursor`
+    ///   when a long run of proposals with other statuses was skipped.
+    pub fn get_withdrawal_proposals(
+        env: Env,
+        status: u32,
+        cursor: u64,
+        limit: u32,
+    ) -> Result<WithdrawalProposalPage, ContractError> {
+        if status > 3 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        // Cap the limit to prevent excessive gas consumption
+        let effective_limit = if limit == 0 || limit > MAX_PAGINATION_LIMIT {
+            MAX_PAGINATION_LIMIT
+        } else {
+            limit
+        };
+
+        let last_id: u64 = env.storage().persistent().get(&PROPOSAL_COUNTER).unwrap_or(0u64);
+        let mut proposals: Vec<WithdrawalProposal> = Vec::new(&env);
+        let mut next_cursor = 0u64;
+
+        // Read at most one page worth of buckets per call
+        let mut bucket = cursor / STATUS_BUCKET_SIZE;
+        let mut buckets_read = 0u32;
+        'buckets: while bucket <= last_id / STATUS_BUCKET_SIZE {
+            if buckets_read == MAX_PAGINATION_LIMIT {
+                next_cursor = bucket * STATUS_BUCKET_SIZE;
+                break;
+            }
+            for proposal_id in get_status_bucket(&env, status, bucket).iter() {
+                if proposal_id < cursor {
+                    continue;
+                }
+                if proposals.len() == effective_limit {
+                    next_cursor = proposal_id;
+                    break 'buckets;
+                }
+                if let Some(proposal) =
+                    env.storage().persistent().get(&(WITHDRAWAL_PROPOSALS, proposal_id))
+                {
+                    proposals.push_back(proposal);
+                }
+            }
+            bucket += 1;
+            buckets_read += 1;
+        }
+
+        Ok(WithdrawalProposalPage {
+            proposals,
+            total_count: get_status_count(&env, status),
+            next_cursor,
+        })
+    }
+
+    /// Backfill the status index and proposal counters for proposals created before they
+    /// existed, `limit` proposal ids at a time (admin only). Proposals still in the storage
+    /// version 1 layout are rewritten in the configured token, so `migrate_storage` must run
+    /// first. Progress is kept between calls, starting from the oldest proposal; returns the
+    /// last proposal id processed so far. Call again until it returns the current proposal count.
+    pub fn migrate_proposal_index(
+        env: Env,
+        admin: Address,
+        limit: u32,
+    ) -> Result<u64, ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+        if limit == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+        if VersionManager::current_version(&env).map_err(map_versioning_error)? < STORAGE_VERSION {
+            return Err(ContractError::InvalidState);
+        }
+
+        let config: TreasuryConfig =
+            env.storage().persistent().get(&CONFIG).ok_or(ContractError::NotInitialized)?;
+        let last_id: u64 = env.storage().persistent().get(&PROPOSAL_COUNTER).unwrap_or(0u64);
+        let migrated: u64 = env.storage().persistent().get(&INDEX_MIGRATION).unwrap_or(0u64);
+        let end = core::cmp::min(migrated.saturating_add(limit as u64), last_id);
+
+        for proposal_id in (migrated + 1)..=end {
+            if let Some(proposal) = load_or_migrate_proposal(&env, proposal_id, &config.token) {
+                backfill_proposal(&env, &proposal);
+            }
+        }
+        env.storage().persistent().set(&INDEX_MIGRATION, &end);
+
+        env.events().publish((Symbol::new(&env, "proposal_index_migrated"), ()), (end, last_id));
+
+        Ok(end)
+    }
+
+    /// Get allocation record for a purpose in an asset
+    pub fn get_allocation(
+        env: Env,
+        asset: Address,
+        purpose: u32,
+    ) -> Result<AllocationRecord, ContractError> {
         env.storage()
             .persistent()
-            .get(&(ALLOCATIONS, purpose))
+            .get(&(ALLOCATIONS, asset, purpose))
             .ok_or(ContractError::NotFound)
     }
 
+    /// Get a single entry of the fee ledger
+    pub fn get_fee_deposit(env: Env, fee_id: u64) -> Result<FeeDeposit, ContractError> {
+        load_fee_deposit(&env, fee_id)
+    }
+
+    /// Number of fee deposits recorded; fee ids run from 1 to this count
+    pub fn get_fee_deposit_count(env: Env) -> u64 {
+        get_fee_count(&env)
+    }
+
+    /// Fee deposits made in `[from_timestamp, to_timestamp)`, oldest first.
+    /// Pass `cursor` 0 for the first page and the returned `next_cursor` for the following
+    /// pages. `limit` is capped at 50; 0 returns a full page.
+    pub fn get_fee_deposits(
+        env: Env,
+        from_timestamp: u64,
+        to_timestamp: u64,
+        cursor: u64,
+        limit: u32,
+    ) -> FeeDepositPage {
+        // Cap the limit to prevent excessive gas consumption
+        let effective_limit = if limit == 0 || limit > MAX_PAGINATION_LIMIT {
+            MAX_PAGINATION_LIMIT
+        } else {
+            limit
+        };
+
+        let count = get_fee_count(&env);
+        let mut fee_id = core::cmp::max(cursor, first_fee_at_or_after(&env, from_timestamp));
+        let mut deposits: Vec<FeeDeposit> = Vec::new(&env);
+        let mut next_cursor = 0u64;
+
+        while fee_id <= count {
+            let deposit = match load_fee_deposit(&env, fee_id) {
+                Ok(deposit) if deposit.timestamp < to_timestamp => deposit,
+                _ => break,
+            };
+            if deposits.len() == effective_limit {
+                next_cursor = fee_id;
+                break;
+            }
+            deposits.push_back(deposit);
+            fee_id += 1;
+        }
+
+        FeeDepositPage { deposits, next_cursor }
+    }
+
+    /// Total fees of one type collected in an asset
+    pub fn get_fees_by_type(env: Env, asset: Address, fee_type: u32) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(FEES_BY_TYPE, asset, fee_type))
+            .unwrap_or(0i128)
+    }
+
+    /// Total fees deposited in an asset by one source contract
+    pub fn get_fees_by_source(env: Env, asset: Address, source_contract: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(FEES_BY_SOURCE, asset, source_contract))
+            .unwrap_or(0i128)
+    }
+
+    /// Budget per epoch for a purpose in an asset (0 = no budget)
+    pub fn get_purpose_budget(env: Env, asset: Address, purpose: u32) -> i128 {
+        get_budget(&env, &asset, purpose)
+    }
+
+    /// Budget, spending and remaining allowance of a purpose in the current epoch
+    pub fn get_budget_utilization(env: Env, asset: Address, purpose: u32) -> BudgetUtilization {
+        budget_utilization(&env, &asset, purpose)
+    }
+
+    /// Current epoch's budget utilization of every allocation purpose in an asset
+    pub fn get_budget_utilizations(env: Env, asset: Address) -> Vec<BudgetUtilization> {
+        let mut utilizations = Vec::new(&env);
+        for purpose in
+            AllocationPurpose::AuditFunding as u32..=AllocationPurpose::CommunityIncentives as u32
+        {
+            utilizations.push_back(budget_utilization(&env, &asset, purpose));
+        }
+        utilizations
+    }
+
     /// Pause/unpause contract (admin only)
-    pub fn set_pause(env: Env, paused: bool) -> Result<(), ContractError> {
-        let caller = env.current_contract_address();
-        require_admin(&env, &caller)?;
+    pub fn set_pause(env: Env, admin: Address, paused: bool) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
         set_paused(&env, paused);
 
         env.events().publish((Symbol::new(&env, "pause_state_changed"), ()), paused);
@@ -696,10 +1845,182 @@ impl TreasuryContract {
         Ok(())
     }
 
+    /// Proposes `new_admin` as the next admin, to be accepted before `expires_at` (admin only).
+    pub fn propose_admin_transfer(
+        env: Env,
+        admin: Address,
+        new_admin: Address,
+        expires_at: u64,
+    ) -> Result<(), ContractError> {
+        validate_address(&env, &new_admin)?;
+        insurance_contracts::authorization::propose_admin_transfer(&env, &admin, &new_admin, expires_at)?;
+        Ok(())
+    }
+
+    /// Withdraws the pending admin transfer (admin only).
+    pub fn cancel_admin_transfer(env: Env, admin: Address) -> Result<(), ContractError> {
+        insurance_contracts::authorization::cancel_admin_transfer(&env, &admin)?;
+        Ok(())
+    }
+
+    /// Completes a pending admin transfer (proposed admin only).
+    pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), ContractError> {
+        insurance_contracts::authorization::accept_admin(&env, &new_admin)?;
+
+        // Keep the admin recorded in the treasury config in sync
+        let mut config: TreasuryConfig =
+            env.storage().persistent().get(&CONFIG).ok_or(ContractError::NotInitialized)?;
+        config.admin = new_admin;
+        env.storage().persistent().set(&CONFIG, &config);
+
+        Ok(())
+    }
+
+    pub fn get_pending_admin(env: Env) -> Option<PendingAdminTransfer> {
+        insurance_contracts::authorization::get_pending_admin(&env)
+    }
+
+    /// Gives up one of the caller's own roles. The admin must transfer `Admin` instead.
+    pub fn renounce_role(env: Env, caller: Address, role: Role) -> Result<(), ContractError> {
+        insurance_contracts::authorization::renounce_role(&env, &caller, role)?;
+        Ok(())
+    }
+
+    /// Get the current holders of a role, paginated by list position (page size capped at 50)
+    pub fn get_role_members(env: Env, role: Role, start_index: u32, limit: u32) -> Vec<Address> {
+        insurance_contracts::authorization::get_role_members(&env, role, start_index, limit)
+    }
+
+    /// Get role grant/revoke/transfer history, oldest first, starting at entry `cursor`
+    pub fn get_role_audit_log(env: Env, cursor: u64, limit: u32) -> Vec<RoleAuditEntry> {
+        insurance_contracts::authorization::get_role_audit_log(&env, cursor, limit)
+    }
+
+    /// Pauses or unpauses the contract as an emergency council action.
+    /// Only callable by the governance contract; the pause is lifted again unless ratified.
+    pub fn set_emergency_pause(env: Env, paused: bool) -> Result<(), ContractError> {
+        let config: TreasuryConfig =
+            env.storage().persistent().get(&CONFIG).ok_or(ContractError::NotInitialized)?;
+        config.governance_contract.require_auth();
+
+        set_paused(&env, paused);
+
+        env.events().publish((Symbol::new(&env, "emergency_pause"), ()), paused);
+
+        Ok(())
+    }
+
+    /// Installs new wasm and migrates to `new_version`.
+    /// Only callable by the configured governance contract when executing a passed
+    /// `ContractUpgrade` proposal.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>, new_version: u32) -> Result<(), ContractError> {
+        let config: TreasuryConfig =
+            env.storage().persistent().get(&CONFIG).ok_or(ContractError::NotInitialized)?;
+        config.governance_contract.require_auth();
+
+        UpgradeableContract::upgrade_wasm(
+            &env,
+            config.governance_contract,
+            new_wasm_hash.clone(),
+            new_version,
+            default_migration_hook,
+        )
+        .map_err(map_versioning_error)?;
+
+        env.events().publish((Symbol::new(&env, "upgraded"), new_version), new_wasm_hash);
+
+        Ok(())
+    }
+
+    pub fn get_version(env: Env) -> Result<u32, ContractError> {
+        VersionManager::current_version(&env).map_err(map_versioning_error)
+    }
+
+    pub fn get_version_history(env: Env) -> Result<Vec<VersionTransition>, ContractError> {
+        VersionManager::version_history(&env).map_err(map_versioning_error)
+    }
+
+    /// Migrates storage written by an earlier contract version to the current layout
+    /// (admin only). Contracts deployed before versioning are treated as version 1; their
+    /// single-asset balances and records are moved under `token`, which becomes the
+    /// treasury's configured token. Follow up with `migrate_proposal_index`.
+    pub fn migrate_storage(env: Env, admin: Address, token: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+        validate_address(&env, &token)?;
+
+        let current = match VersionManager::current_version(&env) {
+            Ok(version) => version,
+            Err(VersioningError::NotInitialized) => {
+                VersionManager::initialize(&env, 1).map_err(map_versioning_error)?;
+                1
+            }
+            Err(e) => return Err(map_versioning_error(e)),
+        };
+
+        VersionManager::migrate(&env, current, STORAGE_VERSION, admin.clone(), |env| {
+            match current {
+                1 => migrate_v1_to_v2(env, &token),
+                _ => Err(VersioningError::InvalidVersionNumber),
+            }
+        })
+        .map_err(map_versioning_error)?;
+
+        env.events().publish(
+            (Symbol::new(&env, "storage_migrated"), current),
+            (STORAGE_VERSION, admin),
+        );
+
+        Ok(())
+    }
+
+    pub fn get_admin(env: Env) -> Result<Address, ContractError> {
+        let config: TreasuryConfig =
+            env.storage().persistent().get(&CONFIG).ok_or(ContractError::NotInitialized)?;
+        Ok(config.admin)
+    }
+
+    /// Governance contract allowed to approve withdrawals and emergency-pause the treasury.
+    pub fn get_governance_contract(env: Env) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get::<_, TreasuryConfig>(&CONFIG)
+            .map(|config| config.governance_contract)
+    }
+
+    /// Whether `contract_address` may deposit fees into the treasury.
+    pub fn is_trusted_contract(env: Env, contract_address: Address) -> bool {
+        is_trusted_contract(&env, &contract_address)
+    }
+
+    /// Primary asset the treasury was initialized with.
+    pub fn get_token(env: Env) -> Result<Address, ContractError> {
+        let config: TreasuryConfig =
+            env.storage().persistent().get(&CONFIG).ok_or(ContractError::NotInitialized)?;
+        Ok(config.token)
+    }
+
+    /// Effective fee in basis points.
+    /// The governance parameter registry takes precedence over the locally configured value.
+    pub fn get_fee_percentage(env: Env) -> Result<u32, ContractError> {
+        let config: TreasuryConfig =
+            env.storage().persistent().get(&CONFIG).ok_or(ContractError::NotInitialized)?;
+
+        let fee = read_parameter(&env, &config.governance_contract, &TREASURY_FEE_BPS)
+            .and_then(|value| u32::try_from(value).ok())
+            .unwrap_or(config.fee_percentage);
+
+        Ok(fee)
+    }
+
     /// Update fee percentage (admin only)
-    pub fn update_fee_percentage(env: Env, new_percentage: u32) -> Result<(), ContractError> {
-        let caller = env.current_contract_address();
-        require_admin(&env, &caller)?;
+    pub fn update_fee_percentage(
+        env: Env,
+        admin: Address,
+        new_percentage: u32,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
 
         if new_percentage == 0 || new_percentage > 10000 {
             return Err(ContractError::InvalidInput);
@@ -727,544 +2048,1109 @@ mod tests {
 
     use super::*;
 
-    fn create_test_env() -> (Env, Address, Address, Address) {
+    fn create_test_env(
+    ) -> (Env, TreasuryContractClient<'static>, Address, Address, Address, Address) {
         let env = Env::default();
-        let admin = Address::random(&env);
-        let governance = Address::random(&env);
-        let trusted_contract = Address::random(&env);
-        (env, admin, governance, trusted_contract)
+        // Depositors authorize the token transfer the treasury makes on their behalf
+        env.mock_all_auths_allowing_non_root_auth();
+        let admin = Address::generate(&env);
+        let governance = Address::generate(&env);
+        let trusted_contract = Address::generate(&env);
+        let token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+        let treasury = env.register(TreasuryContract, ());
+        let client = TreasuryContractClient::new(&env, &treasury);
+        (env, client, admin, governance, trusted_contract, token)
+    }
+
+    /// Initializes the treasury and registers `trusted_contract` as a fee source
+    fn setup_treasury(
+    ) -> (Env, TreasuryContractClient<'static>, Address, Address, Address, Address) {
+        let (env, client, admin, governance, trusted, token) = create_test_env();
+        client.initialize(&admin, &governance, &token, &500);
+        client.register_trusted_contract(&admin, &trusted);
+        (env, client, admin, governance, trusted, token)
+    }
+
+    /// Approve a withdrawal as governance would, passing on the proposal's own terms
+    fn approve_with_tally(
+        client: &TreasuryContractClient,
+        proposal_id: u64,
+        governance_proposal_id: u64,
+        yes_votes: i128,
+        no_votes: i128,
+    ) -> Result<(), ContractError> {
+        let (recipient, asset, amount, _) = client.get_withdrawal_terms(&proposal_id);
+        client
+            .try_approve_proposal(
+                &proposal_id,
+                &governance_proposal_id,
+                &recipient,
+                &asset,
+                &amount,
+                &yes_votes,
+                &no_votes,
+            )
+            .map(|_| ())
+            .map_err(|err| err.unwrap())
+    }
+
+    /// Creates a depositor holding `amount` of the treasury token
+    fn funded_depositor(env: &Env, token: &Address, amount: i128) -> Address {
+        let depositor = Address::generate(env);
+        token::StellarAssetClient::new(env, token).mint(&depositor, &amount);
+        depositor
     }
 
     #[test]
     fn test_initialize_treasury() {
-        let (env, admin, governance, _) = create_test_env();
-
-        let result = TreasuryContract::initialize(
-            env.clone(),
-            admin.clone(),
-            governance.clone(),
-            500, // 5% fee
-        );
+        let (_env, client, admin, governance, _, token) = create_test_env();
 
-        assert!(result.is_ok());
+        client.initialize(&admin, &governance, &token, &500); // 5% fee
 
         // Verify initial state
-        let stats = TreasuryContract::get_stats(env.clone()).unwrap();
+        let stats = client.get_stats(&token);
         assert_eq!(stats.total_balance, 0);
         assert_eq!(stats.total_fees_collected, 0);
         assert_eq!(stats.total_withdrawn, 0);
+        assert_eq!(client.get_admin(), admin);
     }
 
     #[test]
     fn test_initialize_already_initialized() {
-        let (env, admin, governance, _) = create_test_env();
+        let (_env, client, admin, governance, _, token) = create_test_env();
 
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
+        client.initialize(&admin, &governance, &token, &500);
 
-        let result =
-            TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500);
-
-        assert_eq!(result, Err(ContractError::AlreadyInitialized));
+        let result = client.try_initialize(&admin, &governance, &token, &500);
+        assert_eq!(result, Err(Ok(ContractError::AlreadyInitialized)));
     }
 
     #[test]
     fn test_initialize_invalid_fee_percentage() {
-        let (env, admin, governance, _) = create_test_env();
+        let (_env, client, admin, governance, _, token) = create_test_env();
 
         // Test with 0%
-        let result =
-            TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 0);
-        assert_eq!(result, Err(ContractError::InvalidInput));
+        let result = client.try_initialize(&admin, &governance, &token, &0);
+        assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
 
         // Test with > 100%
-        let result =
-            TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 10001);
-        assert_eq!(result, Err(ContractError::InvalidInput));
+        let result = client.try_initialize(&admin, &governance, &token, &10001);
+        assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
     }
 
     #[test]
     fn test_register_trusted_contract() {
-        let (env, admin, governance, trusted) = create_test_env();
+        let (env, client, admin, governance, trusted, token) = create_test_env();
+
+        client.initialize(&admin, &governance, &token, &500);
 
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
+        // Only the admin can register fee sources
+        let outsider = Address::generate(&env);
+        let result = client.try_register_trusted_contract(&outsider, &trusted);
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+        assert!(!client.is_trusted_contract(&trusted));
 
-        let result = TreasuryContract::register_trusted_contract(env.clone(), trusted.clone());
-        assert!(result.is_ok());
+        client.register_trusted_contract(&admin, &trusted);
+        assert!(client.is_trusted_contract(&trusted));
     }
 
     #[test]
     fn test_deposit_premium_fee_without_trust() {
-        let (env, admin, governance, untrusted) = create_test_env();
+        let (env, client, admin, governance, untrusted, token) = create_test_env();
 
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
+        client.initialize(&admin, &governance, &token, &500);
 
-        let depositor = Address::random(&env);
-        let result = TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 1000);
+        let depositor = funded_depositor(&env, &token, 100_000);
+        let result = client.try_deposit_premium_fee(&untrusted, &depositor, &token, &1000);
+        assert_eq!(result, Err(Ok(ContractError::NotTrustedContract)));
 
-        assert_eq!(result, Err(ContractError::NotTrustedContract));
+        // The treasury does not vouch for deposits made in its own name
+        let result = client.try_deposit_premium_fee(&client.address, &depositor, &token, &1000);
+        assert_eq!(result, Err(Ok(ContractError::NotTrustedContract)));
     }
 
     #[test]
     fn test_deposit_premium_fee_success() {
-        let (env, admin, governance, trusted) = create_test_env();
-
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
+        let (env, client, _, _, trusted, token) = setup_treasury();
 
         env.ledger().set_sequence_number(1);
-        env.mock_all_auths();
 
-        let depositor = Address::random(&env);
-        let result = TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 1000);
+        let depositor = funded_depositor(&env, &token, 100_000);
+        client.deposit_premium_fee(&trusted, &depositor, &token, &1000);
 
-        assert!(result.is_ok());
+        // The depositing contract authorized the deposit
+        assert!(env.auths().iter().any(|(address, _)| *address == trusted));
 
-        let balance = TreasuryContract::get_balance(env.clone());
+        let balance = client.get_balance(&token);
         assert_eq!(balance, 1000);
 
-        let stats = TreasuryContract::get_stats(env.clone()).unwrap();
+        let stats = client.get_stats(&token);
         assert_eq!(stats.total_fees_collected, 1000);
     }
 
     #[test]
     fn test_deposit_premium_fee_invalid_amount() {
-        let (env, admin, governance, trusted) = create_test_env();
+        let (env, client, _, _, trusted, token) = setup_treasury();
 
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
+        let depositor = funded_depositor(&env, &token, 100_000);
+        let result = client.try_deposit_premium_fee(&trusted, &depositor, &token, &0);
+        assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
 
-        env.mock_all_auths();
-
-        let depositor = Address::random(&env);
-        let result = TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 0);
-        assert_eq!(result, Err(ContractError::InvalidAmount));
-
-        let result = TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), -100);
-        assert_eq!(result, Err(ContractError::InvalidAmount));
+        let result = client.try_deposit_premium_fee(&trusted, &depositor, &token, &-100);
+        assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
     }
 
     #[test]
     fn test_deposit_claim_penalty() {
-        let (env, admin, governance, trusted) = create_test_env();
-
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
+        let (env, client, _, _, trusted, token) = setup_treasury();
 
-        env.mock_all_auths();
+        let depositor = funded_depositor(&env, &token, 100_000);
+        client.deposit_claim_penalty(&trusted, &depositor, &token, &5000);
 
-        let depositor = Address::random(&env);
-        let result = TreasuryContract::deposit_claim_penalty(env.clone(), depositor.clone(), 5000);
-
-        assert!(result.is_ok());
-
-        let balance = TreasuryContract::get_balance(env.clone());
+        let balance = client.get_balance(&token);
         assert_eq!(balance, 5000);
     }
 
     #[test]
     fn test_deposit_slashing_fee() {
-        let (env, admin, governance, trusted) = create_test_env();
-
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
-
-        env.mock_all_auths();
+        let (env, client, _, _, trusted, token) = setup_treasury();
 
-        let depositor = Address::random(&env);
-        let result = TreasuryContract::deposit_slashing_fee(env.clone(), depositor.clone(), 2000);
+        let depositor = funded_depositor(&env, &token, 100_000);
+        client.deposit_slashing_fee(&trusted, &depositor, &token, &2000);
 
-        assert!(result.is_ok());
-
-        let balance = TreasuryContract::get_balance(env.clone());
+        let balance = client.get_balance(&token);
         assert_eq!(balance, 2000);
     }
 
     #[test]
     fn test_multiple_fee_deposits() {
-        let (env, admin, governance, trusted) = create_test_env();
-
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
-
-        env.mock_all_auths();
+        let (env, client, _, _, trusted, token) = setup_treasury();
 
-        let depositor = Address::random(&env);
+        let depositor = funded_depositor(&env, &token, 100_000);
 
-        TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 1000).unwrap();
-        TreasuryContract::deposit_claim_penalty(env.clone(), depositor.clone(), 500).unwrap();
-        TreasuryContract::deposit_slashing_fee(env.clone(), depositor.clone(), 250).unwrap();
+        client.deposit_premium_fee(&trusted, &depositor, &token, &1000);
+        client.deposit_claim_penalty(&trusted, &depositor, &token, &500);
+        client.deposit_slashing_fee(&trusted, &depositor, &token, &250);
 
-        let balance = TreasuryContract::get_balance(env.clone());
+        let balance = client.get_balance(&token);
         assert_eq!(balance, 1750);
 
-        let stats = TreasuryContract::get_stats(env.clone()).unwrap();
+        let stats = client.get_stats(&token);
         assert_eq!(stats.total_fees_collected, 1750);
     }
 
     #[test]
     fn test_deposit_fee_generic() {
-        let (env, admin, governance, trusted) = create_test_env();
+        let (env, client, _, _, trusted, token) = setup_treasury();
 
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
+        let depositor = funded_depositor(&env, &token, 100_000);
+        client.deposit_fee(&trusted, &depositor, &token, &3000, &4); // Other fee type
 
-        env.mock_all_auths();
-
-        let depositor = Address::random(&env);
-        let result = TreasuryContract::deposit_fee(env.clone(), depositor.clone(), 3000, 4); // Other fee type
-
-        assert!(result.is_ok());
-
-        let balance = TreasuryContract::get_balance(env.clone());
+        let balance = client.get_balance(&token);
         assert_eq!(balance, 3000);
     }
 
     #[test]
     fn test_deposit_when_paused() {
-        let (env, admin, governance, trusted) = create_test_env();
-
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
-
-        env.mock_all_auths();
-
-        TreasuryContract::set_pause(env.clone(), true).unwrap();
+        let (env, client, admin, _, trusted, token) = setup_treasury();
 
-        let depositor = Address::random(&env);
-        let result = TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 1000);
+        client.set_pause(&admin, &true);
 
-        assert_eq!(result, Err(ContractError::Paused));
+        let depositor = funded_depositor(&env, &token, 100_000);
+        let result = client.try_deposit_premium_fee(&trusted, &depositor, &token, &1000);
+        assert_eq!(result, Err(Ok(ContractError::Paused)));
     }
 
     #[test]
     fn test_propose_withdrawal_success() {
-        let (env, admin, governance, trusted) = create_test_env();
-
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
-
-        env.mock_all_auths();
+        let (env, client, _, _, trusted, token) = setup_treasury();
 
         // Deposit funds first
-        let depositor = Address::random(&env);
-        TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 10000).unwrap();
+        let depositor = funded_depositor(&env, &token, 100_000);
+        client.deposit_premium_fee(&trusted, &depositor, &token, &100_000);
 
         // Create withdrawal proposal
-        let recipient = Address::random(&env);
-        let proposer = Address::random(&env);
-        let result = TreasuryContract::propose_withdrawal(
-            env.clone(),
-            proposer.clone(),
-            recipient.clone(),
-            5000,
-            1, // AuditFunding
-            Symbol::new(&env, "Audit funding"),
+        let recipient = Address::generate(&env);
+        let proposer = Address::generate(&env);
+        let proposal_id = client.propose_withdrawal(
+            &proposer,
+            &recipient,
+            &token,
+            &10_000,
+            &1, // AuditFunding
+            &Symbol::new(&env, "audit_funding"),
         );
-
-        assert!(result.is_ok());
-        let proposal_id = result.unwrap();
         assert_eq!(proposal_id, 1);
 
         // Verify proposal exists
-        let proposal = TreasuryContract::get_proposal(env.clone(), proposal_id).unwrap();
-        assert_eq!(proposal.amount, 5000);
+        let proposal = client.get_proposal(&proposal_id);
+        assert_eq!(proposal.amount, 10_000);
         assert_eq!(proposal.recipient, recipient);
         assert_eq!(proposal.status, 0); // Active
     }
 
     #[test]
     fn test_propose_withdrawal_insufficient_funds() {
-        let (env, admin, governance, trusted) = create_test_env();
-
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
-
-        env.mock_all_auths();
-
-        // Deposit only 1000 funds
-        let depositor = Address::random(&env);
-        TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 1000).unwrap();
-
-        // Try to propose withdrawal of 5000
-        let recipient = Address::random(&env);
-        let proposer = Address::random(&env);
-        let result = TreasuryContract::propose_withdrawal(
-            env.clone(),
-            proposer.clone(),
-            recipient.clone(),
-            5000,
-            1,
-            Symbol::new(&env, "Audit funding"),
+        let (env, client, _, _, trusted, token) = setup_treasury();
+
+        // Deposit only 10000 funds
+        let depositor = funded_depositor(&env, &token, 100_000);
+        client.deposit_premium_fee(&trusted, &depositor, &token, &10_000);
+
+        // Try to propose withdrawal of 20000
+        let recipient = Address::generate(&env);
+        let proposer = Address::generate(&env);
+        let result = client.try_propose_withdrawal(
+            &proposer,
+            &recipient,
+            &token,
+            &20_000,
+            &1,
+            &Symbol::new(&env, "audit_funding"),
         );
 
-        assert_eq!(result, Err(ContractError::InsufficientFunds));
+        assert_eq!(result, Err(Ok(ContractError::InsufficientFunds)));
     }
 
     #[test]
     fn test_approve_and_execute_withdrawal() {
-        let (env, admin, governance, trusted) = create_test_env();
-
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
-
-        env.mock_all_auths();
+        let (env, client, admin, _, trusted, token) = setup_treasury();
 
         // Deposit funds
-        let depositor = Address::random(&env);
-        TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 10000).unwrap();
+        let depositor = funded_depositor(&env, &token, 100_000);
+        client.deposit_premium_fee(&trusted, &depositor, &token, &100_000);
 
         // Create withdrawal proposal
-        let recipient = Address::random(&env);
-        let proposer = Address::random(&env);
-        let proposal_id = TreasuryContract::propose_withdrawal(
-            env.clone(),
-            proposer.clone(),
-            recipient.clone(),
-            5000,
-            1,
-            Symbol::new(&env, "Audit funding"),
-        )
-        .unwrap();
-
-        // Simulate time passing beyond voting period (7 days)
-        env.ledger().set_timestamp(7 * 24 * 60 * 60 + 1);
+        let recipient = Address::generate(&env);
+        let proposer = Address::generate(&env);
+        let proposal_id = client.propose_withdrawal(
+            &proposer,
+            &recipient,
+            &token,
+            &10_000,
+            &1,
+            &Symbol::new(&env, "audit_funding"),
+        );
 
-        // Approve proposal
-        let approve_result = TreasuryContract::approve_proposal(env.clone(), proposal_id);
-        assert!(approve_result.is_ok());
+        // Governance approves the proposal after its vote passed
+        approve_with_tally(&client, proposal_id, 1, 600, 100).unwrap();
 
-        // Verify proposal status changed to approved
-        let proposal = TreasuryContract::get_proposal(env.clone(), proposal_id).unwrap();
+        // Verify proposal status changed to approved and the tally was recorded
+        let proposal = client.get_proposal(&proposal_id);
         assert_eq!(proposal.status, 1); // Approved
+        assert_eq!(proposal.governance_proposal_id, 1);
+        assert_eq!(proposal.yes_votes, 600);
+        assert_eq!(proposal.no_votes, 100);
+
+        // Approved withdrawals stay locked up before they can be executed
+        let result = client.try_execute_withdrawal(&admin, &proposal_id);
+        assert_eq!(result, Err(Ok(ContractError::LockupPeriodActive)));
+
+        // Only the admin executes withdrawals
+        env.ledger().set_timestamp(proposal.approved_at + TREASURY_LOCKUP_SECONDS);
+        let result = client.try_execute_withdrawal(&proposer, &proposal_id);
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
 
         // Execute withdrawal
-        let execute_result = TreasuryContract::execute_withdrawal(env.clone(), proposal_id);
-        assert!(execute_result.is_ok());
+        client.execute_withdrawal(&admin, &proposal_id);
 
         // Verify balance decreased
-        let balance = TreasuryContract::get_balance(env.clone());
-        assert_eq!(balance, 5000);
+        let balance = client.get_balance(&token);
+        assert_eq!(balance, 90_000);
+
+        // Verify tokens reached the recipient
+        let token_client = token::Client::new(&env, &token);
+        assert_eq!(token_client.balance(&recipient), 10_000);
 
         // Verify proposal marked as executed
-        let proposal = TreasuryContract::get_proposal(env.clone(), proposal_id).unwrap();
+        let proposal = client.get_proposal(&proposal_id);
         assert!(proposal.executed);
 
         // Verify total withdrawn increased
-        let stats = TreasuryContract::get_stats(env.clone()).unwrap();
-        assert_eq!(stats.total_withdrawn, 5000);
+        let stats = client.get_stats(&token);
+        assert_eq!(stats.total_withdrawn, 10_000);
     }
 
     #[test]
     fn test_execute_withdrawal_insufficient_funds() {
-        let (env, admin, governance, trusted) = create_test_env();
-
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
-
-        env.mock_all_auths();
-
-        // Deposit only 2000 funds
-        let depositor = Address::random(&env);
-        TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 2000).unwrap();
-
-        // Create withdrawal proposal for 5000
-        let recipient = Address::random(&env);
-        let proposer = Address::random(&env);
-        let proposal_id = TreasuryContract::propose_withdrawal(
-            env.clone(),
-            proposer.clone(),
-            recipient.clone(),
-            1500,
-            1,
-            Symbol::new(&env, "Audit funding"),
-        )
-        .unwrap();
-
-        env.ledger().set_timestamp(7 * 24 * 60 * 60 + 1);
+        let (env, client, admin, _, trusted, token) = setup_treasury();
+
+        // Deposit only 100000 funds
+        let depositor = funded_depositor(&env, &token, 100_000);
+        client.deposit_premium_fee(&trusted, &depositor, &token, &100_000);
+
+        // Create withdrawal proposal for 10000
+        let recipient = Address::generate(&env);
+        let proposer = Address::generate(&env);
+        let proposal_id = client.propose_withdrawal(
+            &proposer,
+            &recipient,
+            &token,
+            &10_000,
+            &1,
+            &Symbol::new(&env, "audit_funding"),
+        );
 
-        TreasuryContract::approve_proposal(env.clone(), proposal_id).unwrap();
+        approve_with_tally(&client, proposal_id, 1, 600, 100).unwrap();
+        env.ledger().set_timestamp(TREASURY_LOCKUP_SECONDS);
 
         // Withdraw some funds to reduce balance
-        let execute_result =
-            TreasuryContract::execute_withdrawal(env.clone(), proposal_id).unwrap();
+        client.execute_withdrawal(&admin, &proposal_id);
 
         // Try to execute same proposal again - should fail
-        let result = TreasuryContract::execute_withdrawal(env.clone(), proposal_id);
-        assert_eq!(result, Err(ContractError::InvalidState)); // Already executed
+        let result = client.try_execute_withdrawal(&admin, &proposal_id);
+        assert_eq!(result, Err(Ok(ContractError::InvalidState))); // Already executed
     }
 
     #[test]
     fn test_reject_proposal() {
-        let (env, admin, governance, trusted) = create_test_env();
-
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
-
-        env.mock_all_auths();
+        let (env, client, admin, _, trusted, token) = setup_treasury();
 
         // Deposit funds
-        let depositor = Address::random(&env);
-        TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 10000).unwrap();
+        let depositor = funded_depositor(&env, &token, 100_000);
+        client.deposit_premium_fee(&trusted, &depositor, &token, &100_000);
 
         // Create withdrawal proposal
-        let recipient = Address::random(&env);
-        let proposer = Address::random(&env);
-        let proposal_id = TreasuryContract::propose_withdrawal(
-            env.clone(),
-            proposer.clone(),
-            recipient.clone(),
-            5000,
-            1,
-            Symbol::new(&env, "Audit funding"),
-        )
-        .unwrap();
+        let recipient = Address::generate(&env);
+        let proposer = Address::generate(&env);
+        let proposal_id = client.propose_withdrawal(
+            &proposer,
+            &recipient,
+            &token,
+            &10_000,
+            &1,
+            &Symbol::new(&env, "audit_funding"),
+        );
 
         // Reject proposal
-        let result = TreasuryContract::reject_proposal(env.clone(), proposal_id);
-        assert!(result.is_ok());
+        client.reject_proposal(&admin, &proposal_id);
 
         // Verify proposal status changed to rejected
-        let proposal = TreasuryContract::get_proposal(env.clone(), proposal_id).unwrap();
+        let proposal = client.get_proposal(&proposal_id);
         assert_eq!(proposal.status, 2); // Rejected
 
         // Try to execute rejected proposal - should fail
-        let execute_result = TreasuryContract::execute_withdrawal(env.clone(), proposal_id);
-        assert_eq!(execute_result, Err(ContractError::ProposalNotApproved));
+        let result = client.try_execute_withdrawal(&admin, &proposal_id);
+        assert_eq!(result, Err(Ok(ContractError::ProposalNotApproved)));
     }
 
     #[test]
-    fn test_pause_unpause() {
-        let (env, admin, governance, trusted) = create_test_env();
+    fn test_approve_proposal_requires_passing_tally() {
+        let (env, client, admin, _, trusted, token) = setup_treasury();
+
+        let depositor = funded_depositor(&env, &token, 100_000);
+        client.deposit_premium_fee(&trusted, &depositor, &token, &100_000);
+
+        let recipient = Address::generate(&env);
+        let proposer = Address::generate(&env);
+        let proposal_id = client.propose_withdrawal(
+            &proposer,
+            &recipient,
+            &token,
+            &10_000,
+            &1,
+            &Symbol::new(&env, "audit_funding"),
+        );
+
+        // Terms that differ from the proposal cannot be approved
+        let result =
+            client.try_approve_proposal(&proposal_id, &1, &recipient, &token, &20_000, &600, &100);
+        assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
+        let result =
+            client.try_approve_proposal(&proposal_id, &1, &proposer, &token, &10_000, &600, &100);
+        assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
+
+        // A tally without a yes majority cannot approve a withdrawal
+        let result = approve_with_tally(&client, proposal_id, 1, 100, 100);
+        assert_eq!(result, Err(ContractError::ThresholdNotMet));
+
+        // Rejected proposals can no longer be approved
+        client.reject_proposal(&admin, &proposal_id);
+        let result = approve_with_tally(&client, proposal_id, 1, 600, 100);
+        assert_eq!(result, Err(ContractError::ProposalNotActive));
+
+        let proposal = client.get_proposal(&proposal_id);
+        assert_eq!(proposal.governance_proposal_id, 0);
+        assert_eq!(proposal.yes_votes, 0);
+    }
 
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
+    #[test]
+    fn test_pause_unpause() {
+        let (env, client, admin, _, trusted, token) = setup_treasury();
 
-        env.mock_all_auths();
+        // Only the admin can pause
+        let result = client.try_set_pause(&trusted, &true);
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
 
         // Pause contract
-        let result = TreasuryContract::set_pause(env.clone(), true);
-        assert!(result.is_ok());
+        client.set_pause(&admin, &true);
 
         // Verify deposits fail
-        let depositor = Address::random(&env);
-        let result = TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 1000);
-        assert_eq!(result, Err(ContractError::Paused));
+        let depositor = funded_depositor(&env, &token, 100_000);
+        let result = client.try_deposit_premium_fee(&trusted, &depositor, &token, &1000);
+        assert_eq!(result, Err(Ok(ContractError::Paused)));
 
         // Unpause contract
-        let result = TreasuryContract::set_pause(env.clone(), false);
-        assert!(result.is_ok());
+        client.set_pause(&admin, &false);
 
         // Verify deposits work again
-        let result = TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 1000);
-        assert!(result.is_ok());
+        client.deposit_premium_fee(&trusted, &depositor, &token, &1000);
+        assert_eq!(client.get_balance(&token), 1000);
     }
 
     #[test]
     fn test_update_fee_percentage() {
-        let (env, admin, governance, _) = create_test_env();
-
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
+        let (_env, client, admin, governance, _, token) = create_test_env();
 
-        env.mock_all_auths();
+        client.initialize(&admin, &governance, &token, &500);
 
         // Update fee percentage
-        let result = TreasuryContract::update_fee_percentage(env.clone(), 1000); // 10%
-        assert!(result.is_ok());
+        client.update_fee_percentage(&admin, &1000); // 10%
+        assert_eq!(client.get_fee_percentage(), 1000);
 
         // Try invalid percentage (> 100%)
-        let result = TreasuryContract::update_fee_percentage(env.clone(), 10001);
-        assert_eq!(result, Err(ContractError::InvalidInput));
+        let result = client.try_update_fee_percentage(&admin, &10001);
+        assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
 
         // Try 0%
-        let result = TreasuryContract::update_fee_percentage(env.clone(), 0);
-        assert_eq!(result, Err(ContractError::InvalidInput));
+        let result = client.try_update_fee_percentage(&admin, &0);
+        assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
+
+        // Governance cannot bypass the admin
+        let result = client.try_update_fee_percentage(&governance, &2000);
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
     }
 
     #[test]
     fn test_allocation_tracking() {
-        let (env, admin, governance, trusted) = create_test_env();
-
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
-
-        env.mock_all_auths();
+        let (env, client, admin, _, trusted, token) = setup_treasury();
 
         // Deposit funds
-        let depositor = Address::random(&env);
-        TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 20000).unwrap();
+        let depositor = funded_depositor(&env, &token, 200_000);
+        client.deposit_premium_fee(&trusted, &depositor, &token, &200_000);
 
         // Create multiple withdrawal proposals
-        let recipient1 = Address::random(&env);
-        let recipient2 = Address::random(&env);
-        let proposer = Address::random(&env);
-
-        let proposal_id1 = TreasuryContract::propose_withdrawal(
-            env.clone(),
-            proposer.clone(),
-            recipient1.clone(),
-            5000,
-            1, // AuditFunding
-            Symbol::new(&env, "Audit 1"),
-        )
-        .unwrap();
-
-        let proposal_id2 = TreasuryContract::propose_withdrawal(
-            env.clone(),
-            proposer.clone(),
-            recipient2.clone(),
-            3000,
-            2, // DevelopmentGrants
-            Symbol::new(&env, "Development grant"),
-        )
-        .unwrap();
+        let recipient1 = Address::generate(&env);
+        let recipient2 = Address::generate(&env);
+        let proposer = Address::generate(&env);
+
+        let proposal_id1 = client.propose_withdrawal(
+            &proposer,
+            &recipient1,
+            &token,
+            &20_000,
+            &1, // AuditFunding
+            &Symbol::new(&env, "audit_1"),
+        );
 
-        env.ledger().set_timestamp(7 * 24 * 60 * 60 + 1);
+        let proposal_id2 = client.propose_withdrawal(
+            &proposer,
+            &recipient2,
+            &token,
+            &15_000,
+            &2, // DevelopmentGrants
+            &Symbol::new(&env, "development_grant"),
+        );
 
         // Approve and execute both proposals
-        TreasuryContract::approve_proposal(env.clone(), proposal_id1).unwrap();
-        TreasuryContract::execute_withdrawal(env.clone(), proposal_id1).unwrap();
+        approve_with_tally(&client, proposal_id1, 1, 600, 100).unwrap();
+        approve_with_tally(&client, proposal_id2, 2, 700, 0).unwrap();
+        env.ledger().set_timestamp(TREASURY_LOCKUP_SECONDS);
 
-        TreasuryContract::approve_proposal(env.clone(), proposal_id2).unwrap();
-        TreasuryContract::execute_withdrawal(env.clone(), proposal_id2).unwrap();
+        client.execute_withdrawal(&admin, &proposal_id1);
+        client.execute_withdrawal(&admin, &proposal_id2);
 
         // Verify allocations
-        let balance = TreasuryContract::get_balance(env.clone());
-        assert_eq!(balance, 12000); // 20000 - 5000 - 3000
+        let balance = client.get_balance(&token);
+        assert_eq!(balance, 165_000); // 200000 - 20000 - 15000
 
-        let stats = TreasuryContract::get_stats(env.clone()).unwrap();
-        assert_eq!(stats.total_withdrawn, 8000);
-        assert_eq!(stats.total_fees_collected, 20000);
-        assert_eq!(stats.total_balance, 12000);
+        let stats = client.get_stats(&token);
+        assert_eq!(stats.total_withdrawn, 35_000);
+        assert_eq!(stats.total_fees_collected, 200_000);
+        assert_eq!(stats.total_balance, 165_000);
     }
 
     #[test]
     fn test_treasury_invariants() {
-        let (env, admin, governance, trusted) = create_test_env();
+        let (env, client, _, _, trusted, token) = setup_treasury();
 
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
-        TreasuryContract::register_trusted_contract(env.clone(), trusted.clone()).unwrap();
-
-        env.mock_all_auths();
-
-        let depositor = Address::random(&env);
+        let depositor = funded_depositor(&env, &token, i128::MAX);
 
         // Test overflow prevention
-        let result =
-            TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), i128::MAX);
-        assert!(result.is_ok());
+        client.deposit_premium_fee(&trusted, &depositor, &token, &i128::MAX);
 
         // Trying to add more should fail
-        let result = TreasuryContract::deposit_premium_fee(env.clone(), depositor.clone(), 1);
-        assert_eq!(result, Err(ContractError::Overflow));
+        let result = client.try_deposit_premium_fee(&trusted, &depositor, &token, &1);
+        assert_eq!(result, Err(Ok(ContractError::Overflow)));
+    }
+
+    #[test]
+    fn test_reconcile_balance_reports_direct_transfers() {
+        let (env, client, _, _, trusted, token) = setup_treasury();
+
+        let depositor = funded_depositor(&env, &token, 100_000);
+        client.deposit_premium_fee(&trusted, &depositor, &token, &4000);
+
+        let reconciliation = client.reconcile_balance(&token);
+        assert_eq!(reconciliation.ledger_balance, 4000);
+        assert_eq!(reconciliation.token_balance, 4000);
+        assert_eq!(reconciliation.difference, 0);
+
+        // Tokens sent straight to the contract are not part of the ledger balance
+        token::Client::new(&env, &token).transfer(&depositor, &client.address, &600);
+
+        let reconciliation = client.reconcile_balance(&token);
+        assert_eq!(reconciliation.ledger_balance, 4000);
+        assert_eq!(reconciliation.token_balance, 4600);
+        assert_eq!(reconciliation.difference, 600);
+    }
+
+    #[test]
+    fn test_balances_tracked_per_asset() {
+        let (env, client, admin, _, trusted, token) = setup_treasury();
+
+        let stake_token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+
+        // Assets must be registered before they are accepted
+        let depositor = funded_depositor(&env, &stake_token, 100_000);
+        let result = client.try_deposit_slashing_fee(&trusted, &depositor, &stake_token, &1000);
+        assert_eq!(result, Err(Ok(ContractError::UnsupportedAsset)));
+
+        // Only the admin can add assets
+        let result = client.try_add_supported_asset(&trusted, &stake_token);
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+        client.add_supported_asset(&admin, &stake_token);
+        let result = client.try_add_supported_asset(&admin, &stake_token);
+        assert_eq!(result, Err(Ok(ContractError::AlreadyExists)));
+
+        let premium_payer = funded_depositor(&env, &token, 100_000);
+        client.deposit_premium_fee(&trusted, &premium_payer, &token, &7000);
+        client.deposit_slashing_fee(&trusted, &depositor, &stake_token, &100_000);
+
+        let balances = client.get_balances();
+        assert_eq!(balances.len(), 2);
+        assert_eq!(balances.get(token.clone()), Some(7000));
+        assert_eq!(balances.get(stake_token.clone()), Some(100_000));
+
+        // Withdraw from the second asset only
+        let recipient = Address::generate(&env);
+        let proposer = Address::generate(&env);
+        let proposal_id = client.propose_withdrawal(
+            &proposer,
+            &recipient,
+            &stake_token,
+            &10_000,
+            &3, // InsuranceReserves
+            &Symbol::new(&env, "reserve_top_up"),
+        );
+
+        approve_with_tally(&client, proposal_id, 1, 600, 100).unwrap();
+        env.ledger().set_timestamp(TREASURY_LOCKUP_SECONDS);
+        client.execute_withdrawal(&admin, &proposal_id);
+
+        assert_eq!(client.get_balance(&token), 7000);
+        assert_eq!(client.get_balance(&stake_token), 90_000);
+        assert_eq!(token::Client::new(&env, &stake_token).balance(&recipient), 10_000);
+
+        let allocation = client.get_allocation(&stake_token, &3);
+        assert_eq!(allocation.total_withdrawn, 10_000);
+        assert_eq!(client.try_get_allocation(&token, &3), Err(Ok(ContractError::NotFound)));
+
+        let stats = client.get_stats(&stake_token);
+        assert_eq!(stats.total_fees_collected, 100_000);
+        assert_eq!(stats.total_withdrawn, 10_000);
+    }
+
+    #[test]
+    fn test_withdrawal_proposal_listings_and_stats() {
+        let (env, client, admin, _, trusted, token) = setup_treasury();
+
+        let depositor = funded_depositor(&env, &token, 200_000);
+        client.deposit_premium_fee(&trusted, &depositor, &token, &200_000);
+
+        let recipient = Address::generate(&env);
+        let proposer = Address::generate(&env);
+        let propose = || {
+            client.propose_withdrawal(
+                &proposer,
+                &recipient,
+                &token,
+                &10_000,
+                &1,
+                &Symbol::new(&env, "audit_funding"),
+            )
+        };
+        let (first, second, third) = (propose(), propose(), propose());
+
+        let stats = client.get_stats(&token);
+        assert_eq!(stats.active_proposals, 3);
+        assert_eq!(stats.completed_proposals, 0);
+
+        // Active proposals, two per page
+        let page = client.get_withdrawal_proposals(&0, &0, &2);
+        assert_eq!(page.total_count, 3);
+        assert_eq!(page.proposals.len(), 2);
+        assert_eq!(page.proposals.get(0).unwrap().proposal_id, first);
+        assert_eq!(page.next_cursor, third);
+        let page = client.get_withdrawal_proposals(&0, &page.next_cursor, &2);
+        assert_eq!(page.proposals.len(), 1);
+        assert_eq!(page.proposals.get(0).unwrap().proposal_id, third);
+        assert_eq!(page.next_cursor, 0);
+        let page = client.get_withdrawal_proposals(&0, &(third + 1), &2);
+        assert_eq!(page.proposals.len(), 0);
+
+        let result = client.try_get_withdrawal_proposals(&4, &0, &10);
+        assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
+
+        approve_with_tally(&client, first, 1, 600, 100).unwrap();
+        approve_with_tally(&client, second, 2, 600, 100).unwrap();
+        client.reject_proposal(&admin, &third);
+        let result = client.try_reject_proposal(&admin, &third);
+        assert_eq!(result, Err(Ok(ContractError::InvalidState)));
+
+        env.ledger().set_timestamp(TREASURY_LOCKUP_SECONDS);
+        client.execute_withdrawal(&admin, &first);
+
+        let page = client.get_withdrawal_proposals(&0, &0, &0);
+        assert_eq!(page.total_count, 0);
+        let page = client.get_withdrawal_proposals(&1, &0, &0);
+        assert_eq!(page.total_count, 1);
+        assert_eq!(page.proposals.get(0).unwrap().proposal_id, second);
+        let page = client.get_withdrawal_proposals(&2, &0, &0);
+        assert_eq!(page.proposals.get(0).unwrap().proposal_id, third);
+        let page = client.get_withdrawal_proposals(&3, &0, &0);
+        assert_eq!(page.total_count, 1);
+        assert_eq!(page.proposals.get(0).unwrap().proposal_id, first);
+        assert_eq!(page.proposals.get(0).unwrap().status, 3); // Executed
+
+        let stats = client.get_stats(&token);
+        assert_eq!(stats.active_proposals, 1);
+        assert_eq!(stats.completed_proposals, 2);
+        assert_eq!(stats.total_allocations, 1);
+
+        let allocation = client.get_allocation(&token, &1);
+        assert_eq!(allocation.total_allocated, 10_000);
+        assert_eq!(allocation.allocation_count, 1);
+    }
+
+    /// Drop the status index and proposal counters, as on a deployment from before they existed
+    fn clear_proposal_index(env: &Env, client: &TreasuryContractClient, asset: &Address) {
+        env.as_contract(&client.address, || {
+            for status in 0..4u32 {
+                env.storage().persistent().remove(&(PROPOSAL_INDEX, status, 0u64));
+                env.storage().persistent().remove(&(STATUS_COUNT, status));
+            }
+            for counter in [ACTIVE_PROPOSALS, COMPLETED_PROPOSALS, ALLOCATION_COUNT] {
+                env.storage().persistent().remove(&(counter, asset.clone()));
+            }
+        });
+    }
+
+    #[test]
+    fn test_migrate_proposal_index_backfills_legacy_proposals() {
+        let (env, client, admin, _, trusted, token) = setup_treasury();
+
+        let depositor = funded_depositor(&env, &token, 200_000);
+        client.deposit_premium_fee(&trusted, &depositor, &token, &200_000);
+
+        let recipient = Address::generate(&env);
+        let proposer = Address::generate(&env);
+        let propose = || {
+            client.propose_withdrawal(
+                &proposer,
+                &recipient,
+                &token,
+                &10_000,
+                &1,
+                &Symbol::new(&env, "audit_funding"),
+            )
+        };
+        let (first, second, third) = (propose(), propose(), propose());
+        approve_with_tally(&client, first, 1, 600, 100).unwrap();
+        approve_with_tally(&client, second, 2, 600, 100).unwrap();
+        client.reject_proposal(&admin, &third);
+        env.ledger().set_timestamp(TREASURY_LOCKUP_SECONDS);
+        client.execute_withdrawal(&admin, &first);
+
+        // Upgraded from a version without the index; a new proposal is indexed right away
+        clear_proposal_index(&env, &client, &token);
+        let fourth = propose();
+
+        let result = client.try_migrate_proposal_index(&proposer, &2);
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+        assert_eq!(client.migrate_proposal_index(&admin, &2), 2);
+        assert_eq!(client.migrate_proposal_index(&admin, &10), fourth);
+        assert_eq!(client.migrate_proposal_index(&admin, &10), fourth);
+
+        let stats = client.get_stats(&token);
+        assert_eq!(stats.active_proposals, 2);
+        assert_eq!(stats.completed_proposals, 2);
+        assert_eq!(stats.total_allocations, 1);
+
+        for (status, proposal_id) in [(0, fourth), (1, second), (2, third), (3, first)] {
+            let page = client.get_withdrawal_proposals(&status, &0, &0);
+            assert_eq!(page.total_count, 1);
+            assert_eq!(page.proposals.get(0).unwrap().proposal_id, proposal_id);
+        }
+    }
+
+    #[test]
+    fn test_migrate_storage_moves_single_asset_records_to_token() {
+        let (env, client, admin, governance, _, token) = create_test_env();
+        let recipient = Address::generate(&env);
+
+        // A deployment from storage version 1 holding a single unnamed asset
+        env.as_contract(&client.address, || {
+            initialize_admin(&env, admin.clone());
+            let storage = env.storage().persistent();
+            storage.set(
+                &CONFIG,
+                &LegacyTreasuryConfig {
+                    admin: admin.clone(),
+                    governance_contract: governance.clone(),
+                    fee_percentage: 500,
+                },
+            );
+            storage.set(&TREASURY_BALANCE, &90_000i128);
+            storage.set(&TOTAL_FEES_COLLECTED, &100_000i128);
+            storage.set(&TOTAL_WITHDRAWN, &10_000i128);
+            storage.set(
+                &(ALLOCATIONS, 1u32),
+                &LegacyAllocationRecord {
+                    purpose: 1,
+                    total_allocated: 10_000,
+                    total_withdrawn: 10_000,
+                    allocation_count: 1,
+                },
+            );
+            for (proposal_id, status) in [(1u64, 3u32), (2, 1)] {
+                storage.set(
+                    &(WITHDRAWAL_PROPOSALS, proposal_id),
+                    &LegacyWithdrawalProposal {
+                        proposal_id,
+                        recipient: recipient.clone(),
+                        amount: 10_000,
+                        purpose: 1,
+                        description: Symbol::new(&env, "audit_funding"),
+                        proposed_by: admin.clone(),
+                        created_at: 0,
+                        voting_ends_at: 604_800,
+                        yes_votes: 600,
+                        no_votes: 100,
+                        status,
+                        executed: status == 3,
+                    },
+                );
+            }
+            storage.set(&PROPOSAL_COUNTER, &2u64);
+        });
+
+        // Legacy proposals can only be rewritten once the treasury has a token
+        let result = client.try_migrate_proposal_index(&admin, &10);
+        assert_eq!(result, Err(Ok(ContractError::NotInitialized)));
+
+        let outsider = Address::generate(&env);
+        let result = client.try_migrate_storage(&outsider, &token);
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+
+        env.ledger().set_timestamp(1_000);
+        client.migrate_storage(&admin, &token);
+        assert_eq!(client.get_version(), STORAGE_VERSION);
+        assert_eq!(client.get_token(), token);
+        assert_eq!(client.get_admin(), admin);
+        assert_eq!(client.get_supported_assets().len(), 1);
+        let result = client.try_migrate_storage(&admin, &token);
+        assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
+
+        assert_eq!(client.get_balance(&token), 90_000);
+        let stats = client.get_stats(&token);
+        assert_eq!(stats.total_fees_collected, 100_000);
+        assert_eq!(stats.total_withdrawn, 10_000);
+        let allocation = client.get_allocation(&token, &1);
+        assert_eq!(allocation.asset, token);
+        assert_eq!(allocation.total_withdrawn, 10_000);
+
+        assert_eq!(client.migrate_proposal_index(&admin, &10), 2);
+        let executed = client.get_proposal(&1);
+        assert_eq!(executed.asset, token);
+        assert!(executed.executed);
+        let approved = client.get_proposal(&2);
+        assert_eq!(approved.asset, token);
+        assert_eq!(approved.approved_at, 1_000);
+
+        let stats = client.get_stats(&token);
+        assert_eq!(stats.active_proposals, 1);
+        assert_eq!(stats.completed_proposals, 1);
+        let page = client.get_withdrawal_proposals(&1, &0, &0);
+        assert_eq!(page.proposals.get(0).unwrap().proposal_id, 2);
+    }
+
+    #[test]
+    fn test_fee_ledger_records_each_deposit() {
+        let (env, client, _, _, trusted, token) = setup_treasury();
+
+        let depositor = funded_depositor(&env, &token, 100_000);
+
+        let result = client.try_deposit_fee(&trusted, &depositor, &token, &300, &9);
+        assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
+
+        env.ledger().set_timestamp(100);
+        client.deposit_premium_fee(&trusted, &depositor, &token, &1000);
+        env.ledger().set_timestamp(200);
+        client.deposit_claim_penalty(&trusted, &depositor, &token, &500);
+        env.ledger().set_timestamp(300);
+        client.deposit_slashing_fee(&trusted, &depositor, &token, &250);
+        env.ledger().set_timestamp(400);
+        client.deposit_fee(&trusted, &depositor, &token, &300, &4);
+        env.ledger().set_timestamp(500);
+        client.deposit_premium_fee(&trusted, &depositor, &token, &2000);
+
+        assert_eq!(client.get_fee_deposit_count(), 5);
+
+        let deposit = client.get_fee_deposit(&1);
+        assert_eq!(deposit.fee_type, FeeType::PremiumFee as u32);
+        assert_eq!(deposit.asset, token);
+        assert_eq!(deposit.amount, 1000);
+        assert_eq!(deposit.depositor, depositor);
+        assert_eq!(deposit.timestamp, 100);
+        assert_eq!(deposit.source_contract, trusted);
+
+        // Totals per fee type and per source
+        assert_eq!(client.get_fees_by_type(&token, &1), 3000);
+        assert_eq!(client.get_fees_by_type(&token, &2), 500);
+        assert_eq!(client.get_fees_by_type(&token, &3), 250);
+        assert_eq!(client.get_fees_by_type(&token, &4), 300);
+        assert_eq!(client.get_fees_by_source(&token, &trusted), 4050);
+
+        // Deposits in [200, 500), two per page
+        let page = client.get_fee_deposits(&200, &500, &0, &2);
+        assert_eq!(page.deposits.len(), 2);
+        assert_eq!(page.deposits.get(0).unwrap().fee_id, 2);
+        assert_eq!(page.deposits.get(1).unwrap().fee_id, 3);
+        assert_eq!(page.next_cursor, 4);
+
+        let page = client.get_fee_deposits(&200, &500, &page.next_cursor, &2);
+        assert_eq!(page.deposits.len(), 1);
+        assert_eq!(page.deposits.get(0).unwrap().fee_type, FeeType::Other as u32);
+        assert_eq!(page.next_cursor, 0);
+
+        // A zero limit returns a full page
+        let page = client.get_fee_deposits(&0, &u64::MAX, &0, &0);
+        assert_eq!(page.deposits.len(), 5);
+        assert_eq!(page.next_cursor, 0);
+
+        let page = client.get_fee_deposits(&600, &700, &0, &10);
+        assert_eq!(page.deposits.len(), 0);
+    }
+
+    #[test]
+    fn test_fee_ledger_attributes_deposits_to_their_source() {
+        let (env, client, admin, _, policy, token) = setup_treasury();
+        let claims = Address::generate(&env);
+        client.register_trusted_contract(&admin, &claims);
+
+        let depositor = funded_depositor(&env, &token, 100_000);
+        client.deposit_premium_fee(&policy, &depositor, &token, &1000);
+        client.deposit_claim_penalty(&claims, &depositor, &token, &400);
+        client.deposit_premium_fee(&policy, &depositor, &token, &600);
+
+        assert_eq!(client.get_fee_deposit(&1).source_contract, policy);
+        assert_eq!(client.get_fee_deposit(&2).source_contract, claims);
+        assert_eq!(client.get_fees_by_source(&token, &policy), 1600);
+        assert_eq!(client.get_fees_by_source(&token, &claims), 400);
+        assert_eq!(client.get_fees_by_source(&token, &client.address), 0);
+    }
+
+    #[test]
+    fn test_spending_limits_and_purpose_budgets() {
+        let (env, client, admin, _, trusted, token) = setup_treasury();
+
+        let depositor = funded_depositor(&env, &token, 500_000);
+        client.deposit_premium_fee(&trusted, &depositor, &token, &500_000);
+
+        let recipient = Address::generate(&env);
+        let proposer = Address::generate(&env);
+        let propose = |amount: i128| {
+            client.try_propose_withdrawal(
+                &proposer,
+                &recipient,
+                &token,
+                &amount,
+                &4, // DaoOperations
+                &Symbol::new(&env, "operations"),
+            )
+        };
+
+        // Below the minimum allocation, and above 10% of the balance in one transaction
+        assert_eq!(
+            propose(MIN_ALLOCATION_AMOUNT - 1),
+            Err(Ok(ContractError::AllocationTooSmall))
+        );
+        assert_eq!(propose(50_001), Err(Ok(ContractError::AllocationCapExceeded)));
+
+        let result = client.try_set_purpose_budget(&proposer, &token, &4, &60_000);
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+        client.set_purpose_budget(&admin, &token, &4, &60_000);
+        let result = client.try_set_purpose_budget(&admin, &token, &6, &60_000);
+        assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
+
+        let first = propose(40_000).unwrap().unwrap();
+        let second = propose(30_000).unwrap().unwrap();
+        approve_with_tally(&client, first, 1, 600, 100).unwrap();
+        approve_with_tally(&client, second, 2, 600, 100).unwrap();
+        env.ledger().set_timestamp(TREASURY_LOCKUP_SECONDS);
+
+        client.execute_withdrawal(&admin, &first);
+
+        let utilization = client.get_budget_utilization(&token, &4);
+        assert_eq!(utilization.budget, 60_000);
+        assert_eq!(utilization.spent, 40_000);
+        assert_eq!(utilization.remaining, 20_000);
+
+        // The second withdrawal no longer fits into this epoch's budget
+        let result = client.try_execute_withdrawal(&admin, &second);
+        assert_eq!(result, Err(Ok(ContractError::BudgetExceeded)));
+        assert_eq!(propose(30_000), Err(Ok(ContractError::BudgetExceeded)));
+
+        // Other purposes are unaffected
+        let utilizations = client.get_budget_utilizations(&token);
+        assert_eq!(utilizations.len(), 5);
+        assert_eq!(utilizations.get(0).unwrap().spent, 0);
+
+        // The budget renews with the next epoch
+        env.ledger().set_timestamp(TREASURY_BUDGET_EPOCH_SECONDS);
+        client.execute_withdrawal(&admin, &second);
+
+        let utilization = client.get_budget_utilization(&token, &4);
+        assert_eq!(utilization.epoch, 1);
+        assert_eq!(utilization.spent, 30_000);
+        assert_eq!(utilization.remaining, 30_000);
+        assert_eq!(client.get_balance(&token), 430_000);
+    }
+
+    /// Vesting of the test stream starts shortly after the approval lock-up has passed
+    const STREAM_START: u64 = TREASURY_LOCKUP_SECONDS + 1_000;
+
+    /// Deposits 100_000 and executes an approved stream of 10_000 vesting over 10_000 seconds
+    /// from `STREAM_START`, with a cliff after 1_000. Returns the stream id and the recipient.
+    fn start_test_stream(
+        env: &Env,
+        client: &TreasuryContractClient,
+        admin: &Address,
+        trusted: &Address,
+        token: &Address,
+    ) -> (u64, Address) {
+        let depositor = funded_depositor(env, token, 100_000);
+        client.deposit_premium_fee(trusted, &depositor, token, &100_000);
+
+        let recipient = Address::generate(env);
+        let proposer = Address::generate(env);
+        let schedule = VestingSchedule {
+            start: STREAM_START,
+            cliff: STREAM_START + 1_000,
+            end: STREAM_START + 10_000,
+        };
+        let stream_id = client.propose_stream(
+            &proposer,
+            &recipient,
+            token,
+            &10_000,
+            &2, // DevelopmentGrants
+            &Symbol::new(env, "dev_grant"),
+            &schedule,
+        );
+
+        approve_with_tally(client, stream_id, 1, 600, 100).unwrap();
+        env.ledger().set_timestamp(TREASURY_LOCKUP_SECONDS);
+        client.execute_withdrawal(admin, &stream_id);
+
+        (stream_id, recipient)
+    }
+
+    #[test]
+    fn test_stream_vests_linearly_after_cliff() {
+        let (env, client, admin, _, trusted, token) = setup_treasury();
+
+        // The cliff must fall within the vesting period
+        let result = client.try_propose_stream(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &token,
+            &10_000,
+            &2,
+            &Symbol::new(&env, "dev_grant"),
+            &VestingSchedule { start: STREAM_START, cliff: 500, end: STREAM_START + 10_000 },
+        );
+        assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
+
+        let (stream_id, recipient) = start_test_stream(&env, &client, &admin, &trusted, &token);
+        let token_client = token::Client::new(&env, &token);
+
+        // Executing a stream reserves the funds without paying anything out
+        assert_eq!(client.get_balance(&token), 100_000);
+        assert_eq!(client.get_reserved_balance(&token), 10_000);
+        assert_eq!(client.get_available_balance(&token), 90_000);
+        assert_eq!(token_client.balance(&recipient), 0);
+
+        env.ledger().set_timestamp(STREAM_START + 500);
+        let result = client.try_claim_vested(&stream_id);
+        assert_eq!(result, Err(Ok(ContractError::NothingToClaim)));
+
+        // Halfway through the vesting period
+        env.ledger().set_timestamp(STREAM_START + 5_000);
+        assert_eq!(client.get_claimable(&stream_id), 5_000);
+        assert_eq!(client.claim_vested(&stream_id), 5_000);
+        assert_eq!(token_client.balance(&recipient), 5_000);
+        assert_eq!(client.get_reserved_balance(&token), 5_000);
+        assert_eq!(client.get_balance(&token), 95_000);
+
+        env.ledger().set_timestamp(STREAM_START + 11_000);
+        assert_eq!(client.claim_vested(&stream_id), 5_000);
+        assert_eq!(token_client.balance(&recipient), 10_000);
+        assert_eq!(client.get_reserved_balance(&token), 0);
+
+        let stream = client.get_stream(&stream_id);
+        assert_eq!(stream.claimed, 10_000);
+
+        let allocation = client.get_allocation(&token, &2);
+        assert_eq!(allocation.total_withdrawn, 10_000);
+    }
+
+    #[test]
+    fn test_cancel_stream_releases_unvested_amount() {
+        let (env, client, admin, _, trusted, token) = setup_treasury();
+
+        let (stream_id, recipient) = start_test_stream(&env, &client, &admin, &trusted, &token);
+        assert_eq!(client.get_budget_utilization(&token, &2).spent, 10_000);
+
+        // A quarter of the stream has vested when governance cancels it
+        env.ledger().set_timestamp(STREAM_START + 2_500);
+        client.cancel_stream(&stream_id);
+
+        assert_eq!(client.get_reserved_balance(&token), 2_500);
+        assert_eq!(client.get_available_balance(&token), 97_500);
+        assert_eq!(client.get_budget_utilization(&token, &2).spent, 2_500);
+
+        let result = client.try_cancel_stream(&stream_id);
+        assert_eq!(result, Err(Ok(ContractError::InvalidState)));
+
+        // The vested part stays claimable after cancellation, nothing more accrues
+        env.ledger().set_timestamp(STREAM_START + 19_000);
+        assert_eq!(client.claim_vested(&stream_id), 2_500);
+        assert_eq!(token::Client::new(&env, &token).balance(&recipient), 2_500);
+        assert_eq!(client.get_reserved_balance(&token), 0);
+        assert_eq!(client.get_balance(&token), 97_500);
     }
 
     #[test]
     fn test_get_proposal_not_found() {
-        let (env, admin, governance, _) = create_test_env();
+        let (_env, client, admin, governance, _, token) = create_test_env();
 
-        TreasuryContract::initialize(env.clone(), admin.clone(), governance.clone(), 500).unwrap();
+        client.initialize(&admin, &governance, &token, &500);
 
-        let result = TreasuryContract::get_proposal(env.clone(), 999);
-        assert_eq!(result, Err(ContractError::NotFound));
+        let result = client.try_get_proposal(&999);
+        assert_eq!(result, Err(Ok(ContractError::NotFound)));
     }
 }
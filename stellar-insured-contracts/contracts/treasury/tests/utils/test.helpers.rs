@@ -1,4 +1,4 @@
-use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env, Symbol};
+use soroban_sdk::{testutils::{Address as _, Ledger}, token, Address, Env, Symbol};
 
 use super::*;  // Your TreasuryContract
 
@@ -7,6 +7,7 @@ pub struct TestEnvironment {
     pub admin: Address,
     pub governance: Address,
     pub trusted_contract: Address,
+    pub token: Address,
     pub treasury_id: Address,
     pub treasury_client: TreasuryContractClient<'static>,
 }
@@ -17,6 +18,7 @@ impl TestEnvironment {
         let admin = Address::generate(&env);
         let governance = Address::generate(&env);
         let trusted_contract = Address::generate(&env);
+        let token = env.register_stellar_asset_contract_v2(admin.clone()).address();
 
         // Setup consistent ledger state
         env.ledger().set_timestamp(1_640_995_200);
@@ -27,16 +29,17 @@ impl TestEnvironment {
         let treasury_client = TreasuryContractClient::new(&env, &treasury_id);
 
         // Initialize treasury
-        treasury_client.initialize(&admin, &governance, &500); // 5%
+        treasury_client.initialize(&admin, &governance, &token, &500); // 5%
 
         // Register trusted contract
-        treasury_client.register_trusted_contract(&trusted_contract);
+        treasury_client.register_trusted_contract(&admin, &trusted_contract);
 
         Self {
             env,
             admin,
             governance,
             trusted_contract,
+            token,
             treasury_id,
             treasury_client,
         }
@@ -45,8 +48,12 @@ impl TestEnvironment {
     pub fn with_balance(&mut self, amount: i128) -> &mut Self {
         // Mock deposit from trusted contract
         self.env.mock_all_auths();
+        token::StellarAssetClient::new(&self.env, &self.token)
+            .mint(&self.trusted_contract, &amount);
         self.treasury_client.deposit_premium_fee(
             &self.trusted_contract,
+            &self.trusted_contract,
+            &self.token,
             &amount
         );
         self